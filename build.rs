@@ -0,0 +1,33 @@
+//! Derive the Unicode Case_Ignorable property, which `core` uses for Final_Sigma but doesn’t expose, from this
+//! toolchain’s own `str::to_lowercase`. So it is always of the same Unicode version.
+
+use std::fmt::Write;
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    let mut ranges: Vec<(char, char)> = Vec::new();
+    let mut str = String::new();
+    for ch in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        // A cased `A` only counts as before `Σ`, if `ch` can be skipped, or is cased itself
+        str.clear();
+        str.extend(['A', ch, 'Σ']);
+        let skipped_or_cased = str.to_lowercase().ends_with('ς');
+        // Nothing cased only counts as after `Σ`, if `ch` can be skipped, or is not cased
+        str.clear();
+        str.extend(['A', 'Σ', ch]);
+        let skipped_or_uncased = str.to_lowercase()[1..].starts_with('ς');
+        if skipped_or_cased && skipped_or_uncased {
+            match ranges.last_mut() {
+                Some((_, last)) if *last as u32 + 1 == ch as u32 => *last = ch,
+                _ => ranges.push((ch, ch)),
+            }
+        }
+    }
+    let mut out = String::from("const CASE_IGNORABLE: &[(char, char)] = &[\n");
+    for (first, last) in ranges {
+        writeln!(out, "    ({first:?}, {last:?}),").unwrap();
+    }
+    out.push_str("];\n");
+    let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("case_ignorable.rs");
+    std::fs::write(path, out).unwrap();
+}
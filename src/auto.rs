@@ -0,0 +1,269 @@
+//! `AutoStringlet`, one concrete type for all kinds over a set of size buckets.
+
+use crate::*;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+macro_rules! auto {
+    ($($size:literal: $fixed:ident $trim:ident $var:ident $slim:ident),+) => {
+        /**
+        An enum over all kinds of stringlet in a set of size buckets, so they can be mixed in one `Vec` or `HashMap`,
+        or be passed around without being generic. Constructors pick the cheapest variant: for the smallest bucket
+        that fits, `Stringlet` if the length matches, `TrimStringlet` if it is one less, else `SlimStringlet`, which
+        unlike `VarStringlet` needs no extra length byte. `VarStringlet` variants only come from converting one. `Eq`,
+        `Ord` and `Hash` are the same as for `str`.
+        ```
+        # use stringlet::{AutoStringlet, Result, stringlet};
+        let codes = [
+            AutoStringlet::from_str("EUR")?,
+            AutoStringlet::from_str("Esperanto")?,
+            stringlet!(var 8: "eo").into(),
+        ];
+        assert!(matches!(codes[0], AutoStringlet::Slim8(_)));
+        assert!(matches!(codes[1], AutoStringlet::Slim16(_)));
+        assert_eq!(codes[2], "eo");
+        assert!(codes.iter().any(|code| code.starts_with("Esp")));
+        # Result::Ok(())
+        ```
+        */
+        #[derive(Copy, Clone)]
+        pub enum AutoStringlet {
+            $(
+                $fixed(Stringlet<$size>),
+                $trim(TrimStringlet<$size>),
+                $var(VarStringlet<$size>),
+                $slim(SlimStringlet<$size>),
+            )+
+        }
+
+        impl AutoStringlet {
+            /// The biggest bucket
+            pub const MAX_LEN: usize = { let sizes = [$($size),+]; sizes[sizes.len() - 1] };
+
+            pub const fn from_str(str: &str) -> Result<Self> {
+                let len = str.len();
+                $(
+                    if len <= $size {
+                        // SAFETY we checked the length and got UTF-8
+                        return Ok(unsafe {
+                            if len == $size {
+                                Self::$fixed(Stringlet::from_str_unchecked(str))
+                            } else if len == $size - 1 {
+                                Self::$trim(TrimStringlet::from_str_unchecked(str))
+                            } else {
+                                Self::$slim(SlimStringlet::from_str_unchecked(str))
+                            }
+                        });
+                    }
+                )+
+                Err(TooLong { len, min: 0, max: Self::MAX_LEN, kind: "AutoStringlet" })
+            }
+
+            #[inline]
+            pub const fn as_str(&self) -> &str {
+                match self {
+                    $(
+                        Self::$fixed(slet) => slet.as_str(),
+                        Self::$trim(slet) => slet.as_str(),
+                        Self::$var(slet) => slet.as_str(),
+                        Self::$slim(slet) => slet.as_str(),
+                    )+
+                }
+            }
+
+            fn inner(&self) -> &dyn Debug {
+                match self {
+                    $(
+                        Self::$fixed(slet) => slet,
+                        Self::$trim(slet) => slet,
+                        Self::$var(slet) => slet,
+                        Self::$slim(slet) => slet,
+                    )+
+                }
+            }
+        }
+
+        $(
+            impl From<Stringlet<$size>> for AutoStringlet {
+                #[inline]
+                fn from(slet: Stringlet<$size>) -> Self {
+                    Self::$fixed(slet)
+                }
+            }
+
+            impl From<TrimStringlet<$size>> for AutoStringlet {
+                #[inline]
+                fn from(slet: TrimStringlet<$size>) -> Self {
+                    Self::$trim(slet)
+                }
+            }
+
+            impl From<VarStringlet<$size>> for AutoStringlet {
+                #[inline]
+                fn from(slet: VarStringlet<$size>) -> Self {
+                    Self::$var(slet)
+                }
+            }
+
+            impl From<SlimStringlet<$size>> for AutoStringlet {
+                #[inline]
+                fn from(slet: SlimStringlet<$size>) -> Self {
+                    Self::$slim(slet)
+                }
+            }
+        )+
+    };
+}
+
+auto! {
+    8: Fixed8 Trim8 Var8 Slim8,
+    16: Fixed16 Trim16 Var16 Slim16,
+    32: Fixed32 Trim32 Var32 Slim32,
+    64: Fixed64 Trim64 Var64 Slim64
+}
+
+impl AutoStringlet {
+    /// An empty `SlimStringlet<8>`
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::Slim8(SlimStringlet::new())
+    }
+
+    /// Convert any stringlet, picking the cheapest variant like `from_str()`.
+    pub const fn from_stringlet<Kind: crate::Kind, const SIZE: usize>(
+        slet: StringletBase<Kind, SIZE>,
+    ) -> Result<Self> {
+        Self::from_str(slet.as_str())
+    }
+
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl Default for AutoStringlet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&str> for AutoStringlet {
+    type Error = error::Error;
+
+    fn try_from(str: &str) -> Result<Self> {
+        Self::from_str(str)
+    }
+}
+
+impl Deref for AutoStringlet {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for AutoStringlet {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for AutoStringlet {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for AutoStringlet {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), fmt)
+    }
+}
+
+impl Debug for AutoStringlet {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "AutoStringlet(")?;
+        self.inner().fmt(fmt)?;
+        write!(fmt, ")")
+    }
+}
+
+// Unlike the kinds, different variants with the same content must be equal, so there are no raw array tricks here.
+
+impl Eq for AutoStringlet {}
+
+impl PartialEq for AutoStringlet {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<str> for AutoStringlet {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for AutoStringlet {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for AutoStringlet {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AutoStringlet {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<str> for AutoStringlet {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+impl<'a> PartialOrd<&'a str> for AutoStringlet {
+    #[inline]
+    fn partial_cmp(&self, other: &&'a str) -> Option<Ordering> {
+        self.as_str().partial_cmp(*other)
+    }
+}
+
+impl Hash for AutoStringlet {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
@@ -0,0 +1,148 @@
+//! `CaseInsensitive`, a wrapper comparing and hashing stringlets regardless of ASCII case.
+
+use crate::*;
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/**
+A stringlet whose `Eq`, `Ord` and `Hash` ignore ASCII case, e.g. as a key for HTTP headers or SQL identifiers. Other
+chars must match exactly, as there is no Unicode case folding. This saves normalizing on every insert and lookup.
+```
+# use stringlet::{CaseInsensitive, stringlet};
+# use std::collections::HashMap;
+let mut headers = HashMap::new();
+headers.insert(CaseInsensitive(stringlet!(v 16: "Content-Type")), "text/plain");
+assert_eq!(headers.get(&CaseInsensitive(stringlet!(v 16: "content-type"))), Some(&"text/plain"));
+assert_ne!(CaseInsensitive(stringlet!("Ärger")), CaseInsensitive(stringlet!("ärger")));
+```
+*/
+#[repr(transparent)]
+#[derive(Copy, Clone, Default)]
+pub struct CaseInsensitive<S>(pub S);
+
+impl<S> CaseInsensitive<S> {
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S> From<S> for CaseInsensitive<S> {
+    #[inline]
+    fn from(slet: S) -> Self {
+        Self(slet)
+    }
+}
+
+impl<S> Deref for CaseInsensitive<S> {
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &S {
+        &self.0
+    }
+}
+
+/// Byte wise `|` 0x20 for `A`–`Z`, SIMD within a register. Non-ASCII bytes, which have the high bit, stay the same,
+/// so neither UTF-8 nor our `TAG` get altered.
+#[inline(always)]
+const fn fold(word: u64) -> u64 {
+    const ONES: u64 = u64::MAX / 0xFF;
+    const HIGH: u64 = ONES * 0x80;
+    let low = word & !HIGH;
+    // high bit set where low 7 bits are ≥ 'A', resp. > 'Z', without carry into the next byte
+    let ge_a = low + ONES * (0x80 - b'A' as u64);
+    let gt_z = low + ONES * (0x80 - b'Z' as u64 - 1);
+    let upper = ge_a & !gt_z & !word & HIGH;
+    word | upper >> 2
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> PartialEq
+    for CaseInsensitive<StringletBase<Kind, SIZE>>
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Only VarStringlet keeps the length outside of str, in a byte we mustn’t fold.
+        if Kind::VAR && self.0.len() != other.0.len() {
+            return false;
+        }
+        // As for ==, the padding makes comparing the whole arrays valid.
+        let (words, rest) = self.0.str.as_chunks::<8>();
+        let (other_words, other_rest) = other.0.str.as_chunks::<8>();
+        words.iter().zip(other_words).all(|(word, other)| {
+            let (word, other) = (u64::from_ne_bytes(*word), u64::from_ne_bytes(*other));
+            word == other || fold(word) == fold(other)
+        }) && rest.eq_ignore_ascii_case(other_rest)
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Eq for CaseInsensitive<StringletBase<Kind, SIZE>> {}
+
+impl<Kind: crate::Kind, const SIZE: usize> PartialOrd
+    for CaseInsensitive<StringletBase<Kind, SIZE>>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Like `str`, but as if lower case
+impl<Kind: crate::Kind, const SIZE: usize> Ord for CaseInsensitive<StringletBase<Kind, SIZE>> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let other = other.0.as_bytes().iter().map(u8::to_ascii_lowercase);
+        self.0
+            .as_bytes()
+            .iter()
+            .map(u8::to_ascii_lowercase)
+            .cmp(other)
+    }
+}
+
+// Must be consistent with Eq, so like str, but on lower case bytes
+impl<Kind: crate::Kind, const SIZE: usize> Hash for CaseInsensitive<StringletBase<Kind, SIZE>> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut str = self.0.str;
+        str.make_ascii_lowercase();
+        state.write(&str[..self.0.len()]);
+        state.write_u8(0xff);
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Debug for CaseInsensitive<StringletBase<Kind, SIZE>> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt.debug_tuple("CaseInsensitive").field(&self.0).finish()
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Display for CaseInsensitive<StringletBase<Kind, SIZE>> {
+    #[inline]
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fold_all_bytes() {
+        for byte in 0..=u8::MAX {
+            for pos in 0..8 {
+                let word = u64::from_ne_bytes([b'a', b'Z', 0, 0xff, b'@', b'[', b'`', b'{']);
+                let mut bytes = word.to_ne_bytes();
+                bytes[pos] = byte;
+                let mut expected = bytes;
+                expected.make_ascii_lowercase();
+                assert_eq!(
+                    fold(u64::from_ne_bytes(bytes)),
+                    u64::from_ne_bytes(expected),
+                    "{byte} at {pos}"
+                );
+            }
+        }
+    }
+}
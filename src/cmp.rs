@@ -2,6 +2,8 @@
 
 use crate::*;
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, string::String};
 use core::cmp::Ordering;
 
 /// A 2<sup>nd</sup> generic `StringletBase`.
@@ -67,20 +69,38 @@ impl_for! {
 }
 
 impl_for! {
-    PartialEq<str>:
+    PartialEq<[u8]>:
 
     #[inline]
-    fn eq(&self, other: &str) -> bool {
+    fn eq(&self, other: &[u8]) -> bool {
         if SIZE == 0 {
             other.is_empty()
         } else if Kind::FIXED {
-            self.str == other.as_bytes()
+            self.str == other
         } else {
-            self.as_bytes() == other.as_bytes()
+            self.as_bytes() == other
         }
     }
 }
 
+impl_for! {
+    <'a> PartialEq<&'a [u8]>:
+
+    #[inline(always)]
+    fn eq(&self, other: &&'a [u8]) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl_for! {
+    PartialEq<str>:
+
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.eq(other.as_bytes())
+    }
+}
+
 impl_for! {
     <'a> PartialEq<&'a str>:
 
@@ -90,6 +110,7 @@ impl_for! {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl_for! {
     PartialEq<String>:
 
@@ -99,6 +120,7 @@ impl_for! {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl_for! {
     <'a> PartialEq<&'a String>:
 
@@ -108,6 +130,36 @@ impl_for! {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl_for! {
+    <'a> PartialEq<Cow<'a, str>>:
+
+    #[inline]
+    fn eq(&self, other: &Cow<'a, str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_for! {
+    PartialEq<Box<str>>:
+
+    #[inline]
+    fn eq(&self, other: &Box<str>) -> bool {
+        self.eq(&**other)
+    }
+}
+
+impl_for! {
+    PartialEq<char>:
+
+    /// Only equal if this is exactly that one `char`.
+    #[inline]
+    fn eq(&self, other: &char) -> bool {
+        self.eq(other.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+}
+
 // ── Ordering ──────────────────────────────────────────────────────
 
 impl_for! {
@@ -139,19 +191,37 @@ impl_for! {
 }
 
 impl_for! {
-    PartialOrd<str>:
+    PartialOrd<[u8]>:
 
     #[inline]
-    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &[u8]) -> Option<Ordering> {
         if SIZE == 0 {
             Some(if other.is_empty() { Ordering::Equal } else { Ordering::Less })
         } else {
             if Kind::FIXED { &self.str[..] } else { self.as_bytes() }
-                .partial_cmp(other.as_bytes())
+                .partial_cmp(other)
         }
     }
 }
 
+impl_for! {
+    <'a> PartialOrd<&'a [u8]>:
+
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&'a [u8]) -> Option<Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
+impl_for! {
+    PartialOrd<str>:
+
+    #[inline(always)]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.partial_cmp(other.as_bytes())
+    }
+}
+
 impl_for! {
     <'a> PartialOrd<&'a str>:
 
@@ -161,6 +231,7 @@ impl_for! {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl_for! {
     PartialOrd<String>:
 
@@ -170,6 +241,7 @@ impl_for! {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl_for! {
     <'a> PartialOrd<&'a String>:
 
@@ -179,6 +251,35 @@ impl_for! {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl_for! {
+    <'a> PartialOrd<Cow<'a, str>>:
+
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Cow<'a, str>) -> Option<Ordering> {
+        self.partial_cmp(&**other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_for! {
+    PartialOrd<Box<str>>:
+
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Box<str>) -> Option<Ordering> {
+        self.partial_cmp(&**other)
+    }
+}
+
+impl_for! {
+    PartialOrd<char>:
+
+    #[inline]
+    fn partial_cmp(&self, other: &char) -> Option<Ordering> {
+        self.partial_cmp(other.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+}
+
 // Needed where explicitly requested, e.g. BTreeMap key
 impl_for! {
     Ord:
@@ -188,3 +289,54 @@ impl_for! {
         self.partial_cmp(other).unwrap()
     }
 }
+
+// ── Reversed ──────────────────────────────────────────────────────
+
+/// Impl `Other == Self` and `Other < Self` by flipping the above impls. `Self` is any stringlet, unless given after
+/// `=>`, preceded by its generic params.
+macro_rules! impl_reversed {
+    (<$lt:lifetime> $type:ty => [$($params:tt)*] $target:ty) => {
+        impl_reversed!(@ [$lt, $($params)*] $type => $target);
+    };
+    (<$lt:lifetime> $type:ty) => {
+        impl_reversed!(<$lt> $type => [Kind: crate::Kind, const SIZE: usize] StringletBase<Kind, SIZE>);
+    };
+    ($type:ty => [$($params:tt)*] $target:ty) => {
+        impl_reversed!(@ [$($params)*] $type => $target);
+    };
+    ($type:ty) => {
+        impl_reversed!($type => [Kind: crate::Kind, const SIZE: usize] StringletBase<Kind, SIZE>);
+    };
+    (@ [$($params:tt)*] $type:ty => $target:ty) => {
+        impl<$($params)*> PartialEq<$target> for $type {
+            #[inline(always)]
+            fn eq(&self, other: &$target) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl<$($params)*> PartialOrd<$target> for $type {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$target) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")] // only needed by SpillStringlet
+pub(crate) use impl_reversed;
+
+impl_reversed!(str);
+impl_reversed!(<'a> &'a str);
+impl_reversed!([u8]);
+impl_reversed!(<'a> &'a [u8]);
+impl_reversed!(char);
+#[cfg(feature = "alloc")]
+impl_reversed!(String);
+#[cfg(feature = "alloc")]
+impl_reversed!(<'a> &'a String);
+#[cfg(feature = "alloc")]
+impl_reversed!(<'a> Cow<'a, str>);
+#[cfg(feature = "alloc")]
+impl_reversed!(Box<str>);
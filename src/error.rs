@@ -1,16 +1,175 @@
+use crate::{Fixed, Kind, Slim, Trim, Var, VarStringlet};
 use core::str::Utf8Error;
 
+/// Prefix of compile time panics from `stringlet!(…)`.
+pub(crate) const MACRO_PREFIX: &str = "stringlet!(…): parameter ";
+/// Prefix of compile time panics from `stringlet!(concat: …)`.
+pub(crate) const CONCAT_PREFIX: &str = "stringlet!(concat: …): ";
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b { a } else { b }
+}
+
+/// Decimal digits of `usize::MAX`.
+const DIGITS: usize = usize::MAX.ilog10() as usize + 1;
+const PREFIX_LEN: usize = max(MACRO_PREFIX.len(), CONCAT_PREFIX.len());
+const KIND_LEN: usize = max(
+    max(Fixed::NAME.len(), Var::NAME.len()),
+    max(Trim::NAME.len(), Slim::NAME.len()),
+);
+/// The longest message, which is `TooShort` with all numbers at `usize::MAX`. The others are checked in the test.
+const MESSAGE_LEN: usize = PREFIX_LEN
+    + "too short: ".len()
+    + DIGITS
+    + " bytes for ".len()
+    + KIND_LEN
+    + ", which takes ".len()
+    + DIGITS
+    + "..=".len()
+    + DIGITS;
+
+pub(crate) type Message = VarStringlet<MESSAGE_LEN>;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
-    /// The stringlet is too long to fit in the given size.
-    TooLong,
-    /// The stringlet is too short to be valid.
-    TooShort,
+    /// The input of `len` bytes is too long for type `kind`, which takes `min..=max`.
+    TooLong {
+        len: usize,
+        min: usize,
+        max: usize,
+        kind: &'static str,
+    },
+    /// The input of `len` bytes is too short for type `kind`, which takes `min..=max`.
+    TooShort {
+        len: usize,
+        min: usize,
+        max: usize,
+        kind: &'static str,
+    },
     Utf8Error(Utf8Error),
+    /// A `Display` or other formatting impl failed, as opposed to the output being too long.
+    FmtError(core::fmt::Error),
+    /// The `index` is beyond the input of `len` bytes.
+    OutOfBounds {
+        index: usize,
+        len: usize,
+    },
+    /// A range’s `start` is after its `end`.
+    ReversedRange {
+        start: usize,
+        end: usize,
+    },
+    /// The `index` is inside a multibyte char.
+    NotCharBoundary {
+        index: usize,
+    },
+    /// Splitting gave `count` fields, where `expected` were needed.
+    FieldCount {
+        count: usize,
+        expected: usize,
+    },
+}
+
+impl Error {
+    /// Same as `Display`, but also at compile time, preceded by `prefix`. Only `Utf8Error` is less detailed than
+    /// `Display`, which also tells what is wrong at that index. This fits every `prefix` and `kind` we pass.
+    pub(crate) const fn message(&self, prefix: &str) -> Message {
+        let mut msg = VarStringlet::new();
+        _ = msg.push_str(prefix);
+        let (len, min, max, kind) = match *self {
+            Self::TooLong {
+                len,
+                min,
+                max,
+                kind,
+            } => {
+                _ = msg.push_str("too long: ");
+                (len, min, max, kind)
+            }
+            Self::TooShort {
+                len,
+                min,
+                max,
+                kind,
+            } => {
+                _ = msg.push_str("too short: ");
+                (len, min, max, kind)
+            }
+            Self::Utf8Error(e) => {
+                _ = msg.push_str("invalid utf-8 from index ");
+                push_usize(&mut msg, e.valid_up_to());
+                return msg;
+            }
+            Self::FmtError(_) => {
+                _ = msg.push_str("formatting failed");
+                return msg;
+            }
+            Self::OutOfBounds { index, len } => {
+                _ = msg.push_str("index ");
+                push_usize(&mut msg, index);
+                _ = msg.push_str(" out of bounds for ");
+                push_usize(&mut msg, len);
+                _ = msg.push_str(" bytes");
+                return msg;
+            }
+            Self::ReversedRange { start, end } => {
+                _ = msg.push_str("range start ");
+                push_usize(&mut msg, start);
+                _ = msg.push_str(" is after its end ");
+                push_usize(&mut msg, end);
+                return msg;
+            }
+            Self::NotCharBoundary { index } => {
+                _ = msg.push_str("index ");
+                push_usize(&mut msg, index);
+                _ = msg.push_str(" is not a char boundary");
+                return msg;
+            }
+            Self::FieldCount { count, expected } => {
+                _ = msg.push_str("split into ");
+                push_usize(&mut msg, count);
+                _ = msg.push_str(" fields, where ");
+                push_usize(&mut msg, expected);
+                _ = msg.push_str(" were expected");
+                return msg;
+            }
+        };
+        push_usize(&mut msg, len);
+        _ = msg.push_str(" bytes for ");
+        _ = msg.push_str(kind);
+        _ = msg.push_str(", which takes ");
+        push_usize(&mut msg, min);
+        _ = msg.push_str("..=");
+        push_usize(&mut msg, max);
+        msg
+    }
+}
+
+/// Const replacement for `write!(msg, "{n}")`.
+const fn push_usize(msg: &mut Message, mut n: usize) {
+    let mut digits = [0; DIGITS];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    // SAFETY only ASCII digits
+    _ = msg.push_str(unsafe { str::from_utf8_unchecked(digits.split_at(i).1) });
 }
 
 impl core::error::Error for Error {}
 
+impl From<core::fmt::Error> for Error {
+    fn from(e: core::fmt::Error) -> Self {
+        Self::FmtError(e)
+    }
+}
+
 impl From<Utf8Error> for Error {
     fn from(e: Utf8Error) -> Self {
         Self::Utf8Error(e)
@@ -28,4 +187,72 @@ mod tests {
         // todo new macro once we update to 1.96 for something else
         assert!(matches!(utf8_error, Error::Utf8Error(_)));
     }
+
+    #[test]
+    fn message_len() {
+        let longest = Error::TooShort {
+            len: usize::MAX,
+            min: usize::MAX,
+            max: usize::MAX,
+            kind: "TrimStringlet",
+        };
+        assert_eq!(longest.message(MACRO_PREFIX).len(), MESSAGE_LEN);
+        let others = [
+            Error::OutOfBounds {
+                index: usize::MAX,
+                len: usize::MAX,
+            },
+            Error::ReversedRange {
+                start: usize::MAX,
+                end: usize::MAX,
+            },
+            Error::NotCharBoundary { index: usize::MAX },
+            Error::FieldCount {
+                count: usize::MAX,
+                expected: usize::MAX,
+            },
+        ];
+        for error in others {
+            assert!(error.message(MACRO_PREFIX).len() < MESSAGE_LEN);
+        }
+    }
+
+    #[test]
+    fn message() {
+        let error = Error::TooLong {
+            len: 1234567,
+            min: 0,
+            max: usize::MAX,
+            kind: "SlimStringlet",
+        };
+        assert_eq!(
+            error.message("> ").as_str(),
+            "> too long: 1234567 bytes for SlimStringlet, which takes 0..=18446744073709551615"
+        );
+        #[allow(invalid_from_utf8)]
+        let error: Error = str::from_utf8(b"ab\xff").unwrap_err().into();
+        assert_eq!(error.message("").as_str(), "invalid utf-8 from index 2");
+        let error: Error = core::fmt::Error.into();
+        assert_eq!(error.message("").as_str(), "formatting failed");
+        let error = Error::OutOfBounds { index: 5, len: 3 };
+        assert_eq!(
+            error.message("").as_str(),
+            "index 5 out of bounds for 3 bytes"
+        );
+        let error = Error::ReversedRange { start: 5, end: 3 };
+        assert_eq!(
+            error.message("").as_str(),
+            "range start 5 is after its end 3"
+        );
+        let error = Error::NotCharBoundary { index: 1 };
+        assert_eq!(error.message("").as_str(), "index 1 is not a char boundary");
+        let error = Error::FieldCount {
+            count: 2,
+            expected: 3,
+        };
+        assert_eq!(
+            error.message("").as_str(),
+            "split into 2 fields, where 3 were expected"
+        );
+    }
 }
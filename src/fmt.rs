@@ -1,17 +1,41 @@
-//! `Display` and `Debug` for stringlet
+//! `Display`, `Debug` and hex/binary dump for stringlet, and `Write` into it
 
 use crate::*;
 
-use core::fmt::{Debug, Display, Formatter, Result};
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Result, UpperHex, Write};
 
+// Like `str`, honoring width, fill, alignment and precision
 impl_for! {
     Display:
 
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
-        write!(fmt, "{}", self.as_str())
+        fmt.pad(self.as_str())
     }
 }
 
+/// Dump the raw storage, including the padding and the length or tag byte. Alternate form prefixes it once.
+macro_rules! impl_dump {
+    ($($trait:ident $prefix:literal $format:literal),+) => {
+        $(
+            impl_for! {
+                $trait:
+
+                fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                    if fmt.alternate() {
+                        fmt.write_str($prefix)?;
+                    }
+                    for byte in self.as_slice() {
+                        write!(fmt, $format, byte)?;
+                    }
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+impl_dump!(LowerHex "0x" "{:02x}", UpperHex "0x" "{:02X}", Binary "0b" "{:08b}");
+
 impl_for! {
     Debug:
 
@@ -56,12 +80,127 @@ impl_for! {
     }
 }
 
+/// `write!()` into a `VarStringlet` or `SlimStringlet`. On overflow this fails and, like `push_str()`, leaves `self`
+/// unchanged, even when earlier pieces of the same `write!()` did fit.
+macro_rules! impl_write {
+    ($($stringlet:ident)+) => {
+        $(
+            impl<const SIZE: usize> Write for $stringlet<SIZE> {
+                #[inline]
+                fn write_str(&mut self, str: &str) -> Result {
+                    self.push_str(str).map_err(|_| core::fmt::Error)
+                }
+
+                #[inline]
+                fn write_char(&mut self, ch: char) -> Result {
+                    self.push(ch).map_err(|_| core::fmt::Error)
+                }
+
+                fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> Result {
+                    let before = *self;
+                    core::fmt::write(self, args).inspect_err(|_| *self = before)
+                }
+            }
+        )+
+    };
+}
+
+impl_write!(VarStringlet SlimStringlet);
+
+/// Formatting target for all kinds, as they can only be constructed once the final length is known.
+pub(crate) struct Buffer<const SIZE: usize> {
+    pub(crate) str: [u8; SIZE],
+    pub(crate) len: usize,
+    /// Rather than failing, cut at the last char boundary that fits.
+    pub(crate) truncate: bool,
+    pub(crate) truncated: bool,
+    /// Without truncate, how many bytes didn’t fit, so we can report the full length.
+    pub(crate) overflow: usize,
+}
+
+impl<const SIZE: usize> Buffer<SIZE> {
+    pub(crate) const fn new(truncate: bool) -> Self {
+        Self {
+            str: [0; SIZE],
+            len: 0,
+            truncate,
+            truncated: false,
+            overflow: 0,
+        }
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        // SAFETY we only ever copy whole strs
+        unsafe { str::from_utf8_unchecked(self.str.split_at(self.len).0) }
+    }
+}
+
+impl<const SIZE: usize> Write for Buffer<SIZE> {
+    fn write_str(&mut self, str: &str) -> Result {
+        if self.truncated {
+            // Also when a Display impl ignored our error, nothing may get appended behind a cut
+            return Err(core::fmt::Error);
+        }
+        let mut end = str.len();
+        if self.overflow > 0 || self.len + end > SIZE {
+            if !self.truncate {
+                // Only count from now on
+                self.overflow += end;
+                return Ok(());
+            }
+            end = SIZE - self.len;
+            while !str.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.truncated = true;
+        }
+        self.str[self.len..self.len + end].copy_from_slice(&str.as_bytes()[..end]);
+        self.len += end;
+        // Stop formatting, so nothing gets appended behind a cut
+        if self.truncated {
+            Err(core::fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl Display for error::Error {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
-        match self {
-            TooLong => write!(fmt, "too long"),
-            TooShort => write!(fmt, "too short"),
+        match *self {
+            TooLong {
+                len,
+                min,
+                max,
+                kind,
+            } => write!(
+                fmt,
+                "too long: {len} bytes for {kind}, which takes {min}..={max}"
+            ),
+            TooShort {
+                len,
+                min,
+                max,
+                kind,
+            } => write!(
+                fmt,
+                "too short: {len} bytes for {kind}, which takes {min}..={max}"
+            ),
             Utf8Error(e) => write!(fmt, "{e}"),
+            FmtError(_) => fmt.write_str("formatting failed"),
+            OutOfBounds { index, len } => {
+                write!(fmt, "index {index} out of bounds for {len} bytes")
+            }
+            ReversedRange { start, end } => {
+                write!(fmt, "range start {start} is after its end {end}")
+            }
+            NotCharBoundary { index } => write!(fmt, "index {index} is not a char boundary"),
+            FieldCount { count, expected } => {
+                write!(
+                    fmt,
+                    "split into {count} fields, where {expected} were expected"
+                )
+            }
         }
     }
 }
@@ -69,16 +208,143 @@ impl Display for error::Error {
 #[cfg(test)]
 mod tests {
     use crate::*;
+    use std::format;
+    use std::string::ToString;
+
+    #[test]
+    fn display_spec() {
+        let slet = VarStringlet::<8>::from_str("aäb").unwrap();
+        for (str, expected) in [
+            (format!("{slet:>6}"), format!("{:>6}", "aäb")),
+            (format!("{slet:<6}|"), format!("{:<6}|", "aäb")),
+            (format!("{slet:*^7}"), format!("{:*^7}", "aäb")),
+            (format!("{slet:.2}"), format!("{:.2}", "aäb")),
+            (format!("{slet:>5.2}"), format!("{:>5.2}", "aäb")),
+            (format!("{slet:2}"), format!("{:2}", "aäb")),
+        ] {
+            assert_eq!(str, expected);
+        }
+        assert_eq!(format!("{:>4}", stringlet!(slim: "x")), "   x");
+    }
+
+    #[test]
+    fn dump() {
+        let slet = VarStringlet::<4>::from_str("ab").unwrap();
+        assert_eq!(format!("{slet:x}"), "6162000002");
+        assert_eq!(format!("{slet:#X}"), "0x6162000002");
+        let slet = TrimStringlet::<3>::from_str("ab").unwrap();
+        assert_eq!(format!("{slet:X}"), "6162C1");
+        let slet = SlimStringlet::<2>::from_str("").unwrap();
+        assert_eq!(format!("{slet:#b}"), "0b0000000011000010");
+        assert_eq!(format!("{:x}", stringlet!("ab")), "6162");
+    }
 
     #[test]
     fn error_display() {
         assert_eq!(
             Stringlet::<1>::from_str("").unwrap_err().to_string(),
-            "too short"
+            "too short: 0 bytes for Stringlet, which takes 1..=1"
+        );
+        assert_eq!(
+            VarStringlet::<2>::from_str("abc").unwrap_err().to_string(),
+            "too long: 3 bytes for VarStringlet, which takes 0..=2"
+        );
+        assert_eq!(
+            TrimStringlet::<5>::from_str("abc").unwrap_err().to_string(),
+            "too short: 3 bytes for TrimStringlet, which takes 4..=5"
         );
+        // Not limited by the compile time message buffer
+        let kind = "ExceedinglyLongAndVerboseNameOfSomeThirdPartyStringletWrapperType";
+        let error = TooLong {
+            len: usize::MAX,
+            min: usize::MAX,
+            max: usize::MAX,
+            kind,
+        };
         assert_eq!(
-            Stringlet::<0>::from_str("a").unwrap_err().to_string(),
-            "too long"
+            error.to_string(),
+            format!(
+                "too long: {0} bytes for {kind}, which takes {0}..={0}",
+                usize::MAX
+            )
         );
+        let error = FieldCount {
+            count: 2,
+            expected: 3,
+        };
+        assert_eq!(error.to_string(), error.message("").as_str());
+    }
+
+    #[test]
+    fn write() {
+        use core::fmt::Write;
+        let mut slet = VarStringlet::<8>::new();
+        let (num, str) = (12, "ab");
+        write!(slet, "{num}-{str}").unwrap();
+        assert_eq!(slet, "12-ab");
+        assert!(write!(slet, "{}", 1234).is_err());
+        assert_eq!(slet, "12-ab");
+        // The first pieces fit, but get undone
+        let (ch, str) = ('x', "yz");
+        assert!(write!(slet, "-{ch}{str}").is_err());
+        assert_eq!(slet, "12-ab");
+
+        let mut slet = SlimStringlet::<8>::new();
+        write!(slet, "{:>4}", 'x').unwrap();
+        assert_eq!(slet, SlimStringlet::<8>::from_str("   x").unwrap());
+    }
+
+    #[test]
+    fn from_fmt() {
+        let slet = Stringlet::<5>::from_fmt(format_args!("{}-{}", 12, "ab"));
+        assert_eq!(slet.unwrap(), "12-ab");
+        let slet = VarStringlet::<4>::from_fmt(format_args!("{}-{}", 12, "ab"));
+        assert_eq!(
+            slet.unwrap_err(),
+            TooLong {
+                len: 5,
+                min: 0,
+                max: 4,
+                kind: "VarStringlet"
+            }
+        );
+        let slet = VarStringlet::<4>::from_fmt_truncating(format_args!("{}-{}", 12, "ab"));
+        assert_eq!(slet.unwrap(), "12-a");
+        let slet = SlimStringlet::<4>::from_fmt_truncating(format_args!("{}{}{}", "aä", "x", 'y'));
+        assert_eq!(slet.unwrap(), "aäx");
+        let slet = TrimStringlet::<5>::from_fmt_truncating(format_args!("{}{}{}", "aä", "x", 'ä'));
+        assert_eq!(slet.unwrap(), "aäx");
+        let slet = Stringlet::<5>::from_fmt_truncating(format_args!("{}{}{}", "aä", "x", 'ä'));
+        assert_eq!(
+            slet.unwrap_err(),
+            TooShort {
+                len: 4,
+                min: 5,
+                max: 5,
+                kind: "Stringlet"
+            }
+        );
+
+        struct Stubborn;
+        impl core::fmt::Display for Stubborn {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let _ = fmt.write_str("ab€");
+                fmt.write_str("x")
+            }
+        }
+        let slet = VarStringlet::<4>::from_fmt_truncating(format_args!("{Stubborn}"));
+        assert_eq!(slet.unwrap(), "ab");
+
+        struct Failing;
+        impl core::fmt::Display for Failing {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.write_str("ab")?;
+                Err(core::fmt::Error)
+            }
+        }
+        let slet = VarStringlet::<8>::from_fmt(format_args!("{Failing}"));
+        assert_eq!(slet.unwrap_err(), FmtError(core::fmt::Error));
+        let slet = VarStringlet::<8>::from_fmt_truncating(format_args!("{Failing}"));
+        assert_eq!(slet.unwrap_err().to_string(), "formatting failed");
     }
 }
@@ -1,24 +1,50 @@
 #![doc = include_str!("../README.md")]
 #![deny(clippy::alloc_instead_of_core, clippy::std_instead_of_core)]
 #![allow(clippy::wildcard_imports)] // only our own
-
-/* #![no_std]
-extern crate alloc; */
+#![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
 use core::marker::PhantomData;
 
+mod auto;
+mod case;
 mod cmp;
 pub mod error;
 mod fmt;
 mod macros;
 mod methods;
 mod new;
+mod ops;
+mod option;
 pub mod prelude;
 mod refs;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "alloc")]
+mod spill;
 mod traits;
+mod unicode;
 mod workaround;
 
+pub use auto::AutoStringlet;
+pub use case::CaseInsensitive;
+/// Where `from_str_padded()` puts the content.
+pub use core::fmt::Alignment as Align;
 pub(crate) use error::Error::*;
+#[doc(hidden)]
+pub use macros::_ConcatPart;
+pub use new::StrPiece;
+pub use ops::concat;
+pub use option::{
+    OptionSlimStringlet, OptionStringlet, OptionStringletBase, OptionTrimStringlet,
+    OptionVarStringlet,
+};
+#[cfg(feature = "alloc")]
+pub use spill::SpillStringlet;
+pub use traits::AnyStringlet;
 pub type Result<T> = core::result::Result<T, error::Error>;
 
 /**
@@ -42,69 +68,42 @@ pub trait Kind {
     const ABBR: u8;
 }
 
-/// Configure constructors of `StringletBase` to have only valid generic parameters.
-#[diagnostic::on_unimplemented(
-    message = "`VarStringlet<{SIZE}>` or `SlimStringlet<{SIZE}>` has excessive SIZE",
-    label = "SIZE must be `0..=255` or `0..=64`",
-    note = "`VarStringlet` cannot be longer than 255 bytes. Consider using `String`!",
-    note = "`SlimStringlet` cannot be longer than 64 bytes. Consider using `VarStringlet`!"
-)]
+/// Formerly bounded `VarStringlet` and `SlimStringlet` constructors. Now every stringlet implements it.
+#[deprecated(note = "excessive sizes now fail at compile time, so this bound can be dropped")]
 #[doc(hidden)]
 pub trait Config<Kind, const SIZE: usize = 16> {}
 
-impl<const SIZE: usize> Config<Fixed, SIZE> for Stringlet<SIZE> {}
+#[allow(deprecated)]
+impl<Kind: crate::Kind, const SIZE: usize> Config<Kind, SIZE> for StringletBase<Kind, SIZE> {}
 
-#[diagnostic::on_unimplemented(
-    message = "`VarStringlet<{SIZE}>` has excessive SIZE",
-    label = "SIZE must be `0..=255`",
-    note = "`VarStringlet` cannot be longer than 255 bytes. Consider using `String`!"
-)]
+/**
+Formerly needed to forward a generic `SIZE` to `VarStringlet`. Now every `VarStringlet` implements it, so existing
+bounds still hold.
+```
+#![allow(deprecated)]
+# use stringlet::{VarConfig, VarStringlet};
+fn create<const SIZE: usize>() -> VarStringlet<SIZE> where VarStringlet<SIZE>: VarConfig<SIZE> {
+    VarStringlet::new()
+}
+assert!(create::<5>().is_empty());
+```
+*/
+#[deprecated(note = "excessive sizes now fail at compile time, so this bound can be dropped")]
 pub trait VarConfig<const SIZE: usize> {}
-// VarConfig implemented by macro below
-impl<const SIZE: usize> Config<Var, SIZE> for VarStringlet<SIZE> where Self: VarConfig<SIZE> {}
 
-impl<const SIZE: usize> Config<Trim, SIZE> for TrimStringlet<SIZE> {}
+#[allow(deprecated)]
+impl<const SIZE: usize> VarConfig<SIZE> for VarStringlet<SIZE> {}
 
-#[diagnostic::on_unimplemented(
-    message = "`SlimStringlet<{SIZE}>` has excessive SIZE",
-    label = "SIZE must be `0..=64`",
-    note = "`SlimStringlet` cannot be longer than 64 bytes. Consider using `VarStringlet`!"
-)]
+/// Formerly needed to forward a generic `SIZE` to `SlimStringlet`. Now every `SlimStringlet` implements it, so
+/// existing bounds still hold.
+#[deprecated(note = "excessive sizes now fail at compile time, so this bound can be dropped")]
 pub trait SlimConfig<const SIZE: usize> {}
-// SlimConfig implemented by macro below
-impl<const SIZE: usize> Config<Slim, SIZE> for SlimStringlet<SIZE> where Self: SlimConfig<SIZE> {}
+
+#[allow(deprecated)]
+impl<const SIZE: usize> SlimConfig<SIZE> for SlimStringlet<SIZE> {}
 
 macro_rules! config {
-    (@@ $stringlet:ident $kind_config:ident $($size:tt)+) => {
-        $(
-            impl $kind_config<$size> for $stringlet<$size> {}
-        )+
-    };
-    (@ $stringlet:ident) => {};
-    (@ $stringlet:ident $kind_config:ident 64) => {
-        config![@@ $stringlet $kind_config
-            // for VarStringlet and SlimStringlet
-            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
-            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60
-            61 62 63 64
-        ];
-    };
-    (@ $stringlet:ident $kind_config:ident 255) => {
-        config![@ $stringlet $kind_config 64];
-        config![@@ $stringlet $kind_config
-            // for VarStringlet
-            65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93
-            94 95 96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116
-            117 118 119 120 121 122 123 124 125 126 127 128 129 130 131 132 133 134 135 136 137 138
-            139 140 141 142 143 144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159 160
-            161 162 163 164 165 166 167 168 169 170 171 172 173 174 175 176 177 178 179 180 181 182
-            183 184 185 186 187 188 189 190 191 192 193 194 195 196 197 198 199 200 201 202 203 204
-            205 206 207 208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223 224 225 226
-            227 228 229 230 231 232 233 234 235 236 237 238 239 240 241 242 243 244 245 246 247 248
-            249 250 251 252 253 254 255
-        ];
-    };
-    ($msg1:literal $msg2:literal $stringlet:ident $kind:ident $const:ident $extra_len:tt $($kind_config:ident $size:tt)?) => {
+    ($msg1:literal $msg2:literal $stringlet:ident $kind:ident $const:ident $extra_len:tt) => {
         #[derive(Copy, Clone)]
         pub enum $kind {}
 
@@ -116,10 +115,7 @@ macro_rules! config {
         }
 
         #[doc = concat!($msg1, " length kind of stringlet", $msg2)]
-        $(#[doc = concat!("\n\nWhen instatiating this with a generic `SIZE`, you need to add this bound:\n```ignore\nwhere ",
-            stringify!($stringlet), "<SIZE>: ", stringify!($kind_config), "<SIZE>\n```")])?
         pub type $stringlet<const SIZE: usize = 16> = StringletBase<$kind, SIZE>;
-        $(config![@ $stringlet $kind_config $size];)?
     };
 }
 
@@ -132,7 +128,7 @@ config!("Variable" ", with one extra byte for the length.
     `VarStringlet<32>`. While for copying the advantage can be at one less, e.g. `VarStringlet<31>`. Size must be `0..=255`.
 
 This is also produced by [`stringlet!(…)`](stringlet!()) with a kind specifier of `var` or `v`."
-    VarStringlet Var VAR u8 VarConfig 255);
+    VarStringlet Var VAR u8);
 config!("Trimmed" ", which optionally trims one last byte, useful for codes
     with minimal length variation like [ISO 639](https://www.iso.org/iso-639-language-code). This is achieved by tagging
     an unused last byte with a UTF-8 niche. The length gets calculated branchlessly with very few ops.
@@ -144,7 +140,7 @@ config!("Slim variable" ", uses a UTF-8 niche: It projects the length into 6 bit
     Hence this is the slowest, albeit by a small margin.
 
 This is also produced by [`stringlet!(…)`](stringlet!()) with a kind specifier of `slim` or `s`."
-    SlimStringlet Slim SLIM () SlimConfig 64);
+    SlimStringlet Slim SLIM ());
 
 /** An inline String of varying size bounds, which can be handled like a primitive type.
 This is the underlying type, which you would not use directly. Instead use one of:
@@ -165,14 +161,11 @@ This is the underlying type, which you would not use directly. Instead use one o
   done branchlessly, there are a few more ops for length calculation. Hence this is the slowest, albeit by a small
   margin.
 
-If you want to create either of `VarStringlet` or `SlimStringlet` generically, you must specify their bounds:
+You can create any of them generically without extra bounds. Only when instantiating with an excessive `SIZE`, will
+constructors fail to compile:
 ```
-use stringlet::{VarStringlet, VarConfig, SlimStringlet, SlimConfig, Result};
-fn create<const SIZE: usize>() -> Result<()>
-where
-    VarStringlet<SIZE>: VarConfig<SIZE>,
-    SlimStringlet<SIZE>: SlimConfig<SIZE>,
-{
+use stringlet::{VarStringlet, SlimStringlet, Result};
+fn create<const SIZE: usize>() -> Result<()> {
     let _var = VarStringlet::<SIZE>::try_from("var")?;
     let _slim: SlimStringlet<SIZE> = "slim".try_into()?;
     Ok(())
@@ -195,33 +188,30 @@ pub struct StringletBase<Kind: crate::Kind, const SIZE: usize> {
 ```ignore
 impl_for! { SomeTrait }
 impl_for! { SomeTrait: impl_body }
-impl_for! { <'a, Config, 2> SomeTrait<self2!()>: impl_body }
+impl_for! { <'a, 2> SomeTrait<self2!()>: impl_body }
 ```
-where `'a`, `Config`, `2` and impl_body are all optional, `2` meaning 2<sup>nd</sup> generic stringlet.
+where `'a`, `2` and impl_body are all optional, `2` meaning 2<sup>nd</sup> generic stringlet.
 */
 macro_rules! impl_for {
     // Split this rule, otherwise compiler says optional <…> is ambiguous
     // $two should match nothing, but marks that 2 was matched.
-    (<$($lt:lifetime $(,)?)? $($config:ident $(,)?)? $(2 $($two:literal)?)?> $trait:ty $(: $($rest:tt)+)?) => {
-        impl_for!(@ $(2 $($two)?)? ($($lt)?) {$($config)?} [] $trait: $($($rest)+)?);
+    (<$($lt:lifetime $(,)?)? $(2 $($two:literal)?)?> $trait:ty $(: $($rest:tt)+)?) => {
+        impl_for!(@ $(2 $($two)?)? ($($lt)?) [] $trait: $($($rest)+)?);
     };
     ($trait:ty $(: $($rest:tt)+)?) => {
-        impl_for!(@ () {} [] $trait: $($($rest)+)?);
+        impl_for!(@ () [] $trait: $($($rest)+)?);
     };
 
-    (@ ($($lt:lifetime)?) {$($config:ident)?} [$($gen:tt)*] $trait:ty: $($rest:tt)*) => {
+    (@ ($($lt:lifetime)?) [$($gen:tt)*] $trait:ty: $($rest:tt)*) => {
         impl<$($lt,)? Kind: crate::Kind, const SIZE: usize, $($gen)*> $trait
         for StringletBase<Kind, SIZE>
-        $(where Self: $config<Kind, SIZE>)?
-        //where Self: Config<Kind, SIZE>
         {
             $($rest)*
         }
     };
-    (@ 2 $lt:tt $config:tt [] $trait:ty: $($rest:tt)*) => {
+    (@ 2 $lt:tt [] $trait:ty: $($rest:tt)*) => {
         impl_for!(@
             $lt
-            $config
             [Kind2: crate::Kind, const SIZE2: usize]
             $trait:
             $($rest)*
@@ -1,8 +1,47 @@
 //! `stringlet!()`
 
+use crate::StringletBase;
+
+/// Get the `str` of a `stringlet!(concat: …)` part, be it a stringlet, a reference to one or a `&str`. This works in
+/// `const` context, where neither traits nor `str::as_str()` do.
+#[doc(hidden)]
+pub struct _ConcatPart<T>(pub T);
+
+impl<'a, Kind: crate::Kind, const SIZE: usize> _ConcatPart<&'a StringletBase<Kind, SIZE>> {
+    #[inline(always)]
+    pub const fn as_str(self) -> &'a str {
+        self.0.as_str()
+    }
+}
+
+impl<'a, Kind: crate::Kind, const SIZE: usize> _ConcatPart<&'a &StringletBase<Kind, SIZE>> {
+    #[inline(always)]
+    pub const fn as_str(self) -> &'a str {
+        self.0.as_str()
+    }
+}
+
+impl<'a> _ConcatPart<&&'a str> {
+    #[inline(always)]
+    pub const fn as_str(self) -> &'a str {
+        self.0
+    }
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! stringlet_base {
+    // literals get turned into &str, like concat!() does
+    (concat[$($done:expr),*]  $part:literal $(, $($rest:tt)*)?) => {
+        $crate::stringlet_base!(concat[$($done,)* ::core::concat!($part)]  $($($rest)*)?)
+    };
+    (concat[$($done:expr),*]  $part:expr $(, $($rest:tt)*)?) => {
+        $crate::stringlet_base!(concat[$($done,)* $crate::_ConcatPart(&($part)).as_str()]  $($($rest)*)?)
+    };
+    (concat[$($done:expr),*]) => {
+        $crate::StringletBase::<_, _>::_from_concat_macro(&[$($done),*])
+    };
+
     // get size parameter, if not yet present
     (param$params:tt  :  $($rest:tt)+) => {
         $crate::stringlet_base!(size$params  $($rest)+)
@@ -85,6 +124,19 @@ The optional configuration is kind and/or size, if present followed by a colon:
 |slim SIZE: |s SIZE: |`SlimStringlet<SIZE>`|
 |_: | |`StringletBase<_, _>`|
 
+With a leading `format` this is the same as [`format_stringlet!(…)`](crate::format_stringlet!()).
+
+With a leading `concat:` this concatenates stringlets, `&str`s and literals, the type being inferred from context.
+Unlike `concat!()` this also takes `const` stringlets and `&str`s. In `const` context a result that doesn’t fit fails
+to compile:
+```
+# use stringlet::{Stringlet, VarStringlet, stringlet};
+const REGION: Stringlet<2> = stringlet!("EU");
+const CODE: VarStringlet<4> = stringlet!(v 4: "FR");
+const KEY: VarStringlet<8> = stringlet!(concat: REGION, "-", CODE, '!');
+assert_eq!(KEY, "EU-FR!");
+```
+
 These are equivalent:
 ```
 # use crate::stringlet::{Stringlet, stringlet};
@@ -106,6 +158,17 @@ assert_eq!(s2, S3);
 */
 #[macro_export]
 macro_rules! stringlet {
+    (format:  $($rest:tt)+) => {
+        $crate::format_stringlet!($($rest)+)
+    };
+    (format  $($rest:tt)+) => {
+        $crate::format_stringlet!($($rest)+)
+    };
+
+    (concat:  $($rest:tt)+) => {
+        $crate::stringlet_base!(concat[]  $($rest)+)
+    };
+
     (_:  $($rest:tt)+) => {
         $crate::stringlet_base!(size(_ _)  $($rest)+)
     };
@@ -139,8 +202,102 @@ macro_rules! stringlet {
     };
 }
 
+/**
+Format into a stringlet, like `format!()` without allocating. This returns a `Result`, which is an error if the output
+doesn’t fit. With a leading `truncate` the output gets cut at the last char boundary that fits. That can only fail if
+the result is too short for the fixed or trim kinds.
+
+As there is no parameter to take the size from, this is either given or inferred from context. The optional
+configuration is kind and/or size, if present followed by a colon:
+
+|Long Spec \| |Short Spec \| |Type |
+|:---|:---|:---|
+| | |`StringletBase<_, _>`|
+|SIZE: | |`Stringlet<SIZE>`|
+|var: |v: |`VarStringlet<_>`|
+|var SIZE: |v SIZE: |`VarStringlet<SIZE>`|
+|trim: |t: |`TrimStringlet<_>`|
+|trim SIZE: |t SIZE: |`TrimStringlet<SIZE>`|
+|slim: |s: |`SlimStringlet<_>`|
+|slim SIZE: |s SIZE: |`SlimStringlet<SIZE>`|
+|_: | |`StringletBase<_, _>`|
+
+```
+# use stringlet::{VarStringlet, format_stringlet, stringlet, Result};
+let (metric, id) = ("cpu", 7);
+let s1 = format_stringlet!(v 10: "{metric}.{id:03}")?;
+let s2: VarStringlet<10> = stringlet!(format: "{}.{:03}", metric, id)?;
+let s3 = format_stringlet!(truncate 5: "{metric}.{id:03}")?;
+assert_eq!(s1, "cpu.007");
+assert_eq!(s2, s1);
+assert_eq!(s3, "cpu.0");
+assert!(format_stringlet!(v 5: "{metric}.{id:03}").is_err());
+# Result::Ok(())
+```
+*/
+#[macro_export]
+macro_rules! format_stringlet {
+    (truncate  $($rest:tt)+) => {
+        $crate::format_stringlet!(@from_fmt_truncating  $($rest)+)
+    };
+    (@$fn:ident  $(_)?:  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(_ _)  $($args)+)
+    };
+    (@$fn:ident  $kind:ident $(  $size:literal)?:  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn[$kind] ($($size)?)  $($args)+)
+    };
+    (@$fn:ident  $size:literal:  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Fixed $size)  $($args)+)
+    };
+
+    // map kind names
+    (@$fn:ident[var]  ($($size:literal)?)  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Var $($size)?)  $($args)+)
+    };
+    (@$fn:ident[v]  ($($size:literal)?)  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Var $($size)?)  $($args)+)
+    };
+    (@$fn:ident[trim]  ($($size:literal)?)  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Trim $($size)?)  $($args)+)
+    };
+    (@$fn:ident[t]  ($($size:literal)?)  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Trim $($size)?)  $($args)+)
+    };
+    (@$fn:ident[slim]  ($($size:literal)?)  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Slim $($size)?)  $($args)+)
+    };
+    (@$fn:ident[s]  ($($size:literal)?)  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(Slim $($size)?)  $($args)+)
+    };
+
+    (@$fn:ident(_ _)  $($args:tt)+) => {
+        $crate::StringletBase::<_, _>::$fn(::core::format_args!($($args)+))
+    };
+    (@$fn:ident($kind:ident)  $($args:tt)+) => {
+        $crate::StringletBase::<$crate::$kind, _>::$fn(::core::format_args!($($args)+))
+    };
+    (@$fn:ident($kind:ident $size:literal)  $($args:tt)+) => {
+        $crate::StringletBase::<$crate::$kind, $size>::$fn(::core::format_args!($($args)+))
+    };
+    (@$fn:ident  $($args:tt)+) => {
+        $crate::format_stringlet!(@$fn(_ _)  $($args)+)
+    };
+    ($($args:tt)+) => {
+        $crate::format_stringlet!(@from_fmt  $($args)+)
+    };
+}
+
 #[cfg(doctest)]
 mod doctests {
+    /**
+    ```compile_fail
+    # use stringlet::{Stringlet, VarStringlet, stringlet};
+    const REGION: Stringlet<2> = stringlet!("EU");
+    const KEY: VarStringlet<4> = stringlet!(concat: REGION, "-", REGION);
+    ```
+    */
+    fn macro_concat_too_long_compile_fail() {}
+
     /**
     ```compile_fail
     # use crate::stringlet::stringlet;
@@ -225,7 +382,9 @@ mod doctests {
 
 #[cfg(test)]
 mod tests {
-    fn cmp<Slet: std::fmt::Debug>(slet: Slet, str: &str) {
+    use std::format;
+
+    fn cmp<Slet: core::fmt::Debug>(slet: Slet, str: &str) {
         assert_eq!(format!("{:?}", slet), str);
     }
     #[test]
@@ -280,4 +439,43 @@ mod tests {
             "[SlimStringlet<5> { str: \"aha\" }, SlimStringlet<5> { str: \"oh\" }]",
         );
     }
+
+    #[test]
+    fn format() {
+        let x = 42;
+        cmp(
+            format_stringlet!(2: "{x}").unwrap(),
+            "Stringlet<2> { str: \"42\" }",
+        );
+        cmp(
+            format_stringlet!(var 3: "{x}").unwrap(),
+            "VarStringlet<3> { str: \"42\" }",
+        );
+        cmp(
+            format_stringlet!(t 3: "{x}").unwrap(),
+            "TrimStringlet<3> { str: \"42\" }",
+        );
+        cmp(
+            format_stringlet!(slim 3: "{x}").unwrap(),
+            "SlimStringlet<3> { str: \"42\" }",
+        );
+        cmp(
+            stringlet!(format v 3: "{}", x).unwrap(),
+            "VarStringlet<3> { str: \"42\" }",
+        );
+        cmp(
+            stringlet!(format truncate s 1: "{}", x).unwrap(),
+            "SlimStringlet<1> { str: \"4\" }",
+        );
+        let truncated: crate::VarStringlet<1> = stringlet!(format truncate: "{x}").unwrap();
+        assert_eq!(truncated, "4");
+
+        let inferred: crate::VarStringlet<3> = format_stringlet!("{x}").unwrap();
+        assert_eq!(inferred, "42");
+        let inferred: crate::SlimStringlet<3> = format_stringlet!(_: "{x}").unwrap();
+        assert_eq!(inferred, "42");
+        let too_long: crate::Result<crate::TrimStringlet<3>> = format_stringlet!(trim: "{x}{x}");
+        assert!(too_long.is_err());
+        assert!(format_stringlet!(1: "{x}").is_err());
+    }
 }
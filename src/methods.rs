@@ -2,7 +2,21 @@
 
 use crate::*;
 
+use core::char::{ToLowercase, ToUppercase};
+
 impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /// The shortest content this type can hold.
+    pub const MIN_LEN: usize = if Kind::FIXED {
+        SIZE
+    } else if Kind::TRIM {
+        SIZE.saturating_sub(1)
+    } else {
+        0
+    };
+
+    /// The longest content this type can hold.
+    pub const MAX_LEN: usize = SIZE;
+
     #[inline(always)]
     pub const fn as_bytes(&self) -> &[u8] {
         if Kind::FIXED {
@@ -21,17 +35,84 @@ impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
         unsafe { str::from_utf8_unchecked(self.as_bytes()) }
     }
 
-    pub const fn try_into<Kind2: crate::Kind, const SIZE2: usize>(self) -> Result<self2!()>
-    where
-        self2!(): Config<Kind2, SIZE2>,
-    {
+    pub const fn try_into<Kind2: crate::Kind, const SIZE2: usize>(self) -> Result<self2!()> {
         <self2!()>::from_stringlet(self)
     }
 
-    /* Once we add appending
+    /**
+    Convert into a type that can hold every value of `Self`, i.e. whose length range includes ours. Anything else
+    fails to compile, so use [`narrow()`](Self::narrow) for that.
+    ```
+    # use stringlet::{stringlet, SlimStringlet, VarStringlet};
+    let var: VarStringlet<8> = stringlet!("abc").widen();
+    assert_eq!(var, "abc");
+    let slim: SlimStringlet<4> = stringlet!(trim 4: "abc").widen();
+    assert_eq!(slim, "abc");
+    ```
+    */
+    #[inline]
+    #[must_use]
+    pub const fn widen<Kind2: crate::Kind, const SIZE2: usize>(self) -> self2!() {
+        const {
+            assert!(
+                <self2!()>::MIN_LEN <= Self::MIN_LEN && Self::MAX_LEN <= <self2!()>::MAX_LEN,
+                "target of widen() can’t hold all values, use narrow()"
+            )
+        }
+        match <self2!()>::from_stringlet(self) {
+            Ok(slet) => slet,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /**
+    Convert into a type that may not be able to hold this value, failing with `TooLong` or `TooShort`.
+    ```
+    # use stringlet::{stringlet, error::Error, Stringlet, TrimStringlet};
+    let var = stringlet!(var 8: "abc");
+    assert_eq!(var.narrow::<_, 3>(), Ok(stringlet!("abc")));
+    assert!(matches!(var.narrow::<stringlet::Fixed, 2>(), Err(Error::TooLong { len: 3, .. })));
+    assert!(matches!(var.narrow::<stringlet::Trim, 5>(), Err(Error::TooShort { min: 4, .. })));
+    ```
+    */
+    #[inline]
+    pub const fn narrow<Kind2: crate::Kind, const SIZE2: usize>(self) -> Result<self2!()> {
+        <self2!()>::from_stringlet(self)
+    }
+
+    /**
+    The inverse of [`from_str_padded()`](Self::from_str_padded), stripping `fill` only from the side(s) `align`
+    padded. Content can’t be told from padding, if `str` had `fill` on that side. Zero padding from
+    [`from_str_zero_padded()`](Self::from_str_zero_padded) is `'0'` aligned right, which keeps at least one digit. A
+    sign keeps the zeros after it, like `-0042`, as the `&str` can’t close that gap, but it parses the same.
+    ```
+    # use stringlet::{Align, Stringlet, Result};
+    let slet = Stringlet::<7>::from_str_padded("a b", ' ', Align::Center)?;
+    assert_eq!(slet.trim_padding(' ', Align::Center), "a b");
+    let slet = Stringlet::<5>::from_str_padded(".ab", '.', Align::Left)?;
+    assert_eq!(slet.trim_padding('.', Align::Left), ".ab");
+    let slet = Stringlet::<5>::from_str_zero_padded("100")?;
+    assert_eq!(slet.trim_padding('0', Align::Right), "100");
+    assert_eq!(stringlet::stringlet!("00000").trim_padding('0', Align::Right), "0");
+    # Result::Ok(())
+    ```
+    */
+    pub fn trim_padding(&self, fill: char, align: Align) -> &str {
+        let str = self.as_str();
+        match align {
+            Align::Left => str.trim_end_matches(fill),
+            Align::Right => match str.trim_start_matches(fill) {
+                "" if fill == '0' => &str[str.len().saturating_sub(1)..],
+                trimmed => trimmed,
+            },
+            Align::Center => str.trim_matches(fill),
+        }
+    }
+
+    #[inline(always)]
     pub const fn capacity(&self) -> usize {
         SIZE
-    } */
+    }
 
     #[inline(always)]
     pub const fn len(&self) -> usize {
@@ -94,3 +175,735 @@ impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
         }
     }
 }
+
+// ── Mutation ──────────────────────────────────────────────────────
+
+/// These are only available for `VarStringlet` and `SlimStringlet`, as other kinds can’t change their length. Every
+/// method keeps the padding, on which fast `==` relies. Growing methods return `TooLong` rather than panicking.
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    ```
+    # use stringlet::{VarStringlet, Result};
+    let mut abc = VarStringlet::<4>::new();
+    abc.push('a')?;
+    abc.push_str("bc")?;
+    assert_eq!(abc, "abc");
+    assert!(abc.push('ä').is_err());
+    assert_eq!(abc, "abc");
+    # Result::Ok(())
+    ```
+    */
+    pub const fn push(&mut self, ch: char) -> Result<()> {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    pub const fn push_str(&mut self, str: &str) -> Result<()> {
+        Self::assert_variable();
+        let len = self.len();
+        let new_len = len + str.len();
+        if new_len > SIZE {
+            return Err(Self::too_long(new_len));
+        }
+        // SAFETY we checked the length and appended UTF-8
+        unsafe {
+            core::ptr::copy_nonoverlapping(str.as_ptr(), self.str.as_mut_ptr().add(len), str.len());
+            self.set_len(new_len);
+        }
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        Self::assert_variable();
+        let ch = self.as_str().chars().next_back()?;
+        // SAFETY we cut at a char boundary
+        unsafe { self.set_len(self.len() - ch.len_utf8()) };
+        Some(ch)
+    }
+
+    /// Shorten to `new_len`, or do nothing if that is not shorter.
+    /// # Panics
+    /// If `new_len` is not at a char boundary.
+    pub const fn truncate(&mut self, new_len: usize) {
+        Self::assert_variable();
+        if new_len < self.len() {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len is not at a char boundary"
+            );
+            // SAFETY we cut at a char boundary
+            unsafe { self.set_len(new_len) };
+        }
+    }
+
+    pub const fn clear(&mut self) {
+        Self::assert_variable();
+        // SAFETY nothing is always valid
+        unsafe { self.set_len(0) };
+    }
+
+    /// # Panics
+    /// If `idx` is beyond `len()` or not at a char boundary.
+    pub const fn insert(&mut self, idx: usize, ch: char) -> Result<()> {
+        self.insert_str(idx, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /**
+    ```
+    # use stringlet::{SlimStringlet, Result};
+    let mut slet: SlimStringlet<8> = "Hello".try_into()?;
+    slet.insert_str(0, ">> ")?;
+    assert_eq!(slet, ">> Hello");
+    assert!(slet.insert_str(2, "!").is_err());
+    # Result::Ok(())
+    ```
+    # Panics
+    If `idx` is beyond `len()` or not at a char boundary.
+    */
+    pub const fn insert_str(&mut self, idx: usize, str: &str) -> Result<()> {
+        Self::assert_variable();
+        let len = self.len();
+        assert!(
+            self.as_str().is_char_boundary(idx),
+            "idx is not at a char boundary"
+        );
+        let new_len = len + str.len();
+        if new_len > SIZE {
+            return Err(Self::too_long(new_len));
+        }
+        // SAFETY we checked the length, and insert UTF-8 at a char boundary
+        unsafe {
+            let at = self.str.as_mut_ptr().add(idx);
+            core::ptr::copy(at, at.add(str.len()), len - idx);
+            core::ptr::copy_nonoverlapping(str.as_ptr(), at, str.len());
+            self.set_len(new_len);
+        }
+        Ok(())
+    }
+
+    /// # Panics
+    /// If `idx` is not at a char boundary, or there is no char at `idx`.
+    pub fn remove(&mut self, idx: usize) -> char {
+        Self::assert_variable();
+        let len = self.len();
+        let Some(ch) = self.as_str()[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a stringlet");
+        };
+        let next = idx + ch.len_utf8();
+        self.str.copy_within(next..len, idx);
+        // SAFETY we removed a whole char
+        unsafe { self.set_len(len - (next - idx)) };
+        ch
+    }
+
+    /**
+    ```
+    # use stringlet::{VarStringlet, Result};
+    let mut slet: VarStringlet<12> = "2025-12-31".try_into()?;
+    slet.retain(|ch| ch != '-');
+    assert_eq!(slet, "20251231");
+    # Result::Ok(())
+    ```
+    */
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        Self::assert_variable();
+        // Work on a copy, so that a panicking f leaves self intact.
+        let mut str = self.str;
+        let mut len = 0;
+        for (idx, ch) in self.char_indices() {
+            if f(ch) {
+                let ch_len = ch.len_utf8();
+                str[len..len + ch_len].copy_from_slice(&self.str[idx..idx + ch_len]);
+                len += ch_len;
+            }
+        }
+        self.str = str;
+        // SAFETY we only kept whole chars
+        unsafe { self.set_len(len) };
+    }
+
+    /**
+    Append all `char`s or `&str`s, or, if they don’t all fit, nothing at all.
+    ```
+    # use stringlet::{VarStringlet, Result};
+    let mut slet: VarStringlet<8> = "abc".try_into()?;
+    slet.try_extend(['-', 'd'])?;
+    assert_eq!(slet, "abc-d");
+    assert!(slet.try_extend(["ef", "ghi"]).is_err());
+    assert_eq!(slet, "abc-d");
+    # Result::Ok(())
+    ```
+    */
+    pub fn try_extend<P: StrPiece>(&mut self, iter: impl IntoIterator<Item = P>) -> Result<()> {
+        Self::assert_variable();
+        let len = self.len();
+        for piece in iter {
+            if let Err(e) = self.push_str(piece.as_str(&mut [0; 4])) {
+                self.truncate(len);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    const fn assert_variable() {
+        const {
+            assert!(
+                Kind::VAR || Kind::SLIM,
+                "only VarStringlet or SlimStringlet can change their length"
+            );
+        }
+    }
+
+    /**
+    Set a new length and pad the rest the same way as `from_utf8_unchecked()`.
+    # Safety
+    It is the callers responsibility to ensure that `len <= SIZE` and that the content up to `len` is UTF-8. */
+    #[inline]
+    pub(crate) const unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(Kind::VAR || Kind::SLIM, "unchecked call");
+        let ptr = self.str.as_mut_ptr();
+        // SAFETY len is up to the caller
+        unsafe {
+            if Kind::VAR {
+                ptr.add(len).write_bytes(0, SIZE - len);
+                self.var_set_last(len as _);
+            } else if SIZE > len {
+                ptr.add(len).write_bytes(0, SIZE - len - 1);
+                ptr.add(SIZE - 1).write(TAG | (SIZE - len) as u8);
+            }
+        }
+    }
+}
+
+// ── ASCII ─────────────────────────────────────────────────────────
+
+/// Const versions of the `str` methods. These only touch the `len()` prefix, so the padding stays intact.
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    #[inline]
+    pub const fn is_ascii(&self) -> bool {
+        self.as_bytes().is_ascii()
+    }
+
+    #[inline]
+    pub const fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
+    }
+
+    pub const fn make_ascii_uppercase(&mut self) {
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            self.str[i] = self.str[i].to_ascii_uppercase();
+            i += 1;
+        }
+    }
+
+    pub const fn make_ascii_lowercase(&mut self) {
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            self.str[i] = self.str[i].to_ascii_lowercase();
+            i += 1;
+        }
+    }
+
+    /**
+    ```
+    # use stringlet::{stringlet, Stringlet};
+    const CODES: [Stringlet<3>; 2] = [stringlet!("eur").to_ascii_uppercase(), stringlet!("usd").to_ascii_uppercase()];
+    assert_eq!(CODES, ["EUR", "USD"]);
+    ```
+    */
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+}
+
+// ── Case ──────────────────────────────────────────────────────────
+
+/// Unicode case mapping into any other stringlet. As this can change the length, e.g. `ß` to `SS`, these return a
+/// `Result`. The `…_widened()` variants are infallible instead, but only compile for targets with room for the worst
+/// case growth, 3 times for upper and title case, or 1.5 times for lower case.
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    ```
+    # use stringlet::{stringlet, Result, VarStringlet};
+    let name = stringlet!(v 8: "Straße");
+    assert_eq!(name.to_uppercase::<stringlet::Var, 8>()?, "STRASSE");
+    assert!(name.to_uppercase::<stringlet::Var, 6>().is_err());
+    let up: VarStringlet<24> = name.to_uppercase_widened();
+    assert_eq!(up, "STRASSE");
+    # Result::Ok(())
+    ```
+    */
+    pub fn to_uppercase<Kind2: crate::Kind, const SIZE2: usize>(&self) -> Result<self2!()> {
+        self.map_case(|_, ch, _| CaseMapping::Upper(ch.to_uppercase()))
+    }
+
+    /// Like `str::to_lowercase`, including a word final `Σ` becoming `ς`.
+    pub fn to_lowercase<Kind2: crate::Kind, const SIZE2: usize>(&self) -> Result<self2!()> {
+        self.map_case(|before, ch, after| {
+            // Unicode’s Final_Sigma: a cased letter before and none after, skipping case ignorable chars
+            fn cased_next(mut chars: impl Iterator<Item = char>) -> bool {
+                chars
+                    .find(|&ch| !unicode::is_case_ignorable(ch))
+                    .is_some_and(unicode::is_cased)
+            }
+            if ch == 'Σ' && cased_next(before.chars().rev()) && !cased_next(after.chars()) {
+                CaseMapping::Other(Some('ς'))
+            } else {
+                CaseMapping::Lower(ch.to_lowercase())
+            }
+        })
+    }
+
+    /**
+    Upper case the first char of each word and lower case the rest. Words start after anything other than letters,
+    digits or apostrophes. This is not Unicode’s Titlecase_Mapping, which `core` doesn’t have, but the full upper case
+    of that first char. So `ß` becomes `SS`, and chars that have a title case of their own, like `ǆ` or `ᾳ`, become
+    `Ǆ` or `ΑΙ`, instead of `ǅ` or `ᾼ`.
+    ```
+    # use stringlet::{stringlet, Result};
+    let name = stringlet!(v 16: "o'neil mcDONALD");
+    assert_eq!(name.to_titlecase::<stringlet::Var, 16>()?, "O'neil Mcdonald");
+    # Result::Ok(())
+    ```
+    */
+    pub fn to_titlecase<Kind2: crate::Kind, const SIZE2: usize>(&self) -> Result<self2!()> {
+        self.map_case(|before, ch, _| {
+            if before
+                .chars()
+                .next_back()
+                .is_some_and(|prev| prev.is_alphanumeric() || prev == '\'' || prev == '’')
+            {
+                CaseMapping::Lower(ch.to_lowercase())
+            } else {
+                CaseMapping::Upper(ch.to_uppercase())
+            }
+        })
+    }
+
+    #[must_use]
+    pub fn to_uppercase_widened<Kind2: crate::Kind, const SIZE2: usize>(&self) -> self2!() {
+        Self::assert_case_room::<Kind2, SIZE2>();
+        match self.to_uppercase() {
+            Ok(slet) => slet,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_lowercase_widened<Kind2: crate::Kind, const SIZE2: usize>(&self) -> self2!() {
+        const {
+            assert!(
+                <self2!()>::MAX_LEN >= SIZE * 3 / 2 && <self2!()>::MIN_LEN <= Self::MIN_LEN / 3,
+                "target of to_lowercase_widened() can’t hold all results, use to_lowercase()"
+            )
+        }
+        match self.to_lowercase() {
+            Ok(slet) => slet,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_titlecase_widened<Kind2: crate::Kind, const SIZE2: usize>(&self) -> self2!() {
+        Self::assert_case_room::<Kind2, SIZE2>();
+        match self.to_titlecase() {
+            Ok(slet) => slet,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Growth is by at most a factor 3, e.g. `ΐ` to `Ϊ́`, and shrinking also, e.g. `K` Kelvin sign to `k`.
+    #[inline(always)]
+    const fn assert_case_room<Kind2: crate::Kind, const SIZE2: usize>() {
+        const {
+            assert!(
+                <self2!()>::MAX_LEN >= SIZE * 3 && <self2!()>::MIN_LEN <= Self::MIN_LEN / 3,
+                "target of to_…case_widened() can’t hold all results, use to_…case()"
+            )
+        }
+    }
+
+    /// Map each char, knowing its neighbours.
+    fn map_case<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        mut f: impl FnMut(&str, char, &str) -> CaseMapping,
+    ) -> Result<self2!()> {
+        let str = self.as_str();
+        let mapped = str
+            .char_indices()
+            .map(|(i, ch)| f(&str[..i], ch, &str[i + ch.len_utf8()..]));
+        <self2!()>::try_from_iter(mapped.flatten())
+    }
+}
+
+/// The different iterators a char can map to.
+enum CaseMapping {
+    Upper(ToUppercase),
+    Lower(ToLowercase),
+    Other(Option<char>),
+}
+
+impl Iterator for CaseMapping {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Self::Upper(chars) => chars.next(),
+            Self::Lower(chars) => chars.next(),
+            Self::Other(ch) => ch.take(),
+        }
+    }
+}
+
+// ── Substrings ────────────────────────────────────────────────────
+
+/// Extract parts into any other stringlet, so they can be kept as `Copy` values. Indices are in bytes and must be at
+/// char boundaries, else these fail with `OutOfBounds`, `ReversedRange` or `NotCharBoundary`.
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    ```
+    # use stringlet::{stringlet, error::Error, Result, Stringlet};
+    let iso = stringlet!("2025-07-14");
+    let month: Stringlet<2> = iso.substring(5..7)?;
+    assert_eq!(month, "07");
+    assert_eq!(iso.substring::<stringlet::Var, 8>(..4)?, "2025");
+    assert_eq!(iso.substring::<stringlet::Var, 8>(8..20), Err(Error::OutOfBounds { index: 20, len: 10 }));
+    assert_eq!(stringlet!("Käse").substring::<stringlet::Var, 8>(2..), Err(Error::NotCharBoundary { index: 2 }));
+    # Result::Ok(())
+    ```
+    */
+    pub fn substring<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        range: impl core::ops::RangeBounds<usize>,
+    ) -> Result<self2!()> {
+        use core::ops::Bound::*;
+        let str = self.as_str();
+        let len = str.len();
+        let start = match range.start_bound() {
+            Included(&start) => start,
+            Excluded(&start) => start.saturating_add(1),
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&end) => end.saturating_add(1),
+            Excluded(&end) => end,
+            Unbounded => len,
+        };
+        if start > len {
+            Err(OutOfBounds { index: start, len })
+        } else if end > len {
+            Err(OutOfBounds { index: end, len })
+        } else if start > end {
+            Err(ReversedRange { start, end })
+        } else if !str.is_char_boundary(start) {
+            Err(NotCharBoundary { index: start })
+        } else if !str.is_char_boundary(end) {
+            Err(NotCharBoundary { index: end })
+        } else {
+            <self2!()>::from_str(&str[start..end])
+        }
+    }
+
+    /**
+    The rest after `prefix`, or `None` if we don’t start with it.
+    ```
+    # use stringlet::{stringlet, Result, VarStringlet};
+    let header = stringlet!(v 32: "X-Request-Id");
+    let name: Option<Result<VarStringlet<16>>> = header.strip_prefix_into("X-");
+    assert_eq!(name, Some(Ok(stringlet!(v 16: "Request-Id"))));
+    assert!(header.strip_prefix_into::<stringlet::Var, 16>("Y-").is_none());
+    ```
+    */
+    pub fn strip_prefix_into<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        prefix: &str,
+    ) -> Option<Result<self2!()>> {
+        self.as_str().strip_prefix(prefix).map(<self2!()>::from_str)
+    }
+
+    /// The rest before `suffix`, or `None` if we don’t end with it.
+    pub fn strip_suffix_into<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        suffix: &str,
+    ) -> Option<Result<self2!()>> {
+        self.as_str().strip_suffix(suffix).map(<self2!()>::from_str)
+    }
+
+    /**
+    Up to `n` chars from the start, fewer if we are shorter. Like for [`substring()`](Self::substring), the target is
+    usually inferred, so only the count needs to be given. It must be able to hold the bytes of those chars.
+    ```
+    # use stringlet::{stringlet, Result, Stringlet, VarStringlet};
+    struct Airport {
+        code: Stringlet<3>,
+        tail: VarStringlet<8>,
+    }
+    let city = stringlet!(v 16: "Zürich");
+    let abbr: VarStringlet<8> = city.first_chars(3)?;
+    assert_eq!(abbr, "Zür");
+    let airport = Airport {
+        code: stringlet!("ZRH").first_chars(3)?,
+        tail: city.last_chars(4)?,
+    };
+    assert_eq!(airport.tail, "rich");
+    assert!(city.first_chars::<stringlet::Fixed, 3>(3).is_err());
+    # assert_eq!(airport.code, "ZRH");
+    # Result::Ok(())
+    ```
+    */
+    pub fn first_chars<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        n: usize,
+    ) -> Result<self2!()> {
+        let str = self.as_str();
+        let end = str.char_indices().nth(n).map_or(str.len(), |(idx, _)| idx);
+        <self2!()>::from_str(&str[..end])
+    }
+
+    /**
+    Up to `n` chars from the end, fewer if we are shorter, into an inferred target like
+    [`first_chars()`](Self::first_chars).
+    ```
+    # use stringlet::{stringlet, Result, VarStringlet};
+    let tail: VarStringlet<4> = stringlet!(v 16: "Zürich").last_chars(3)?;
+    assert_eq!(tail, "ich");
+    # Result::Ok(())
+    ```
+    */
+    pub fn last_chars<Kind2: crate::Kind, const SIZE2: usize>(&self, n: usize) -> Result<self2!()> {
+        let str = self.as_str();
+        let start = match n {
+            0 => str.len(),
+            _ => str.char_indices().nth_back(n - 1).map_or(0, |(idx, _)| idx),
+        };
+        <self2!()>::from_str(&str[start..])
+    }
+}
+
+// ── Splitting ─────────────────────────────────────────────────────
+
+/// Split into any other stringlet, so fields can be kept without allocating. The separator `pat` is a [`StrPiece`],
+/// and each piece fails individually, if it doesn’t fit the target.
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    Like `str::split`, also for an empty `pat`.
+    ```
+    # use stringlet::{stringlet, Result, VarStringlet};
+    let line = stringlet!(v 32: "GET /index.html HTTP/1.1");
+    let mut fields = line.split_into::<stringlet::Var, 12>(' ');
+    assert_eq!(fields.next(), Some(Ok(stringlet!(v 12: "GET"))));
+    assert_eq!(fields.next(), Some(Ok(stringlet!(v 12: "/index.html"))));
+    assert_eq!(fields.next(), Some(Ok(stringlet!(v 12: "HTTP/1.1"))));
+    assert_eq!(fields.next(), None);
+    ```
+    */
+    pub fn split_into<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        pat: impl StrPiece,
+    ) -> impl Iterator<Item = Result<self2!()>> {
+        self.split_pieces(pat).map(<self2!()>::from_str)
+    }
+
+    /// Like `str::split_whitespace`.
+    pub fn split_whitespace_into<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+    ) -> impl Iterator<Item = Result<self2!()>> {
+        self.as_str().split_whitespace().map(<self2!()>::from_str)
+    }
+
+    /**
+    Split into exactly `M` fields, like [`stringlet!([…])`](stringlet!()) gives an array of stringlets. Any other
+    number of fields fails with `FieldCount`.
+    ```
+    # use stringlet::{stringlet, error::Error, Result, VarStringlet};
+    let [from, to, rate]: [VarStringlet<4>; 3] = stringlet!(v 16: "EUR:USD:1.07").split_array(':')?;
+    assert_eq!([from, to, rate], ["EUR", "USD", "1.07"]);
+    let pair: Result<[VarStringlet<4>; 2]> = stringlet!("EUR:USD:1.07").split_array(':');
+    assert_eq!(pair, Err(Error::FieldCount { count: 3, expected: 2 }));
+    # Result::Ok(())
+    ```
+    */
+    pub fn split_array<Kind2: crate::Kind, const SIZE2: usize, const M: usize>(
+        &self,
+        pat: impl StrPiece,
+    ) -> Result<[self2!(); M]> {
+        let mut pieces = self.split_pieces(pat);
+        let mut count = 0;
+        let mut error = None;
+        let fields: [Option<self2!()>; M] = core::array::from_fn(|_| {
+            let piece = pieces.next()?;
+            count += 1;
+            <self2!()>::from_str(piece)
+                .map_err(|e| _ = error.get_or_insert(e))
+                .ok()
+        });
+        let count = count + pieces.count();
+        if count != M {
+            Err(FieldCount { count, expected: M })
+        } else if let Some(e) = error {
+            Err(e)
+        } else {
+            Ok(fields.map(Option::unwrap))
+        }
+    }
+
+    /// The `&str` pieces for the above, as `core::str::pattern::Pattern` is not stable.
+    fn split_pieces(&self, pat: impl StrPiece) -> impl Iterator<Item = &str> {
+        let mut rest = Some(self.as_str());
+        // An empty pat also matches before the first char
+        let mut leading = true;
+        core::iter::from_fn(move || {
+            let str = rest?;
+            let mut buf = [0; 4];
+            let sep = pat.as_str(&mut buf);
+            let (piece, tail) = if !sep.is_empty() {
+                match str.split_once(sep) {
+                    Some((piece, tail)) => (piece, Some(tail)),
+                    None => (str, None),
+                }
+            } else if leading {
+                leading = false;
+                ("", Some(str))
+            } else {
+                match str.chars().next() {
+                    Some(ch) => (&str[..ch.len_utf8()], Some(&str[ch.len_utf8()..])),
+                    None => ("", None),
+                }
+            };
+            rest = tail;
+            Some(piece)
+        })
+    }
+}
+
+// ── Replacing ─────────────────────────────────────────────────────
+
+/// Like the `str` methods, but into any other stringlet, or in place.
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    Replace all matches of `from`, a [`StrPiece`], with `to`.
+    ```
+    # use stringlet::{stringlet, Result, VarStringlet};
+    let path = stringlet!(v 16: "a/b/c");
+    let sep: VarStringlet<16> = path.replace("/", "::")?;
+    assert_eq!(sep, "a::b::c");
+    assert!(path.replace::<stringlet::Var, 6>('/', "::").is_err());
+    # Result::Ok(())
+    ```
+    */
+    pub fn replace<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        from: impl StrPiece,
+        to: &str,
+    ) -> Result<self2!()> {
+        self.replacen(from, to, usize::MAX)
+    }
+
+    /// Replace the first `count` matches of `from` with `to`.
+    pub fn replacen<Kind2: crate::Kind, const SIZE2: usize>(
+        &self,
+        from: impl StrPiece,
+        to: &str,
+        count: usize,
+    ) -> Result<self2!()> {
+        let mut buf = [0; 4];
+        let from = from.as_str(&mut buf);
+        let parts = self.as_str().splitn(count.saturating_add(1), from);
+        <self2!()>::try_from_iter(
+            parts
+                .enumerate()
+                .flat_map(|(i, part)| [if i == 0 { "" } else { to }, part]),
+        )
+    }
+
+    /**
+    Replace all `from` with `to` in place. If both have the same UTF-8 length, e.g. ASCII, this only overwrites those
+    bytes, so it works for all kinds. Otherwise the length changes, which may fail like `try_from_iter()`, leaving
+    `self` unchanged.
+    ```
+    # use stringlet::{stringlet, Result};
+    let mut key = stringlet!("user.name");
+    key.replace_char('.', '_')?;
+    assert_eq!(key, "user_name");
+    assert!(key.replace_char('_', 'ü').is_err());
+    # Result::Ok(())
+    ```
+    */
+    pub fn replace_char(&mut self, from: char, to: char) -> Result<()> {
+        if from.len_utf8() != to.len_utf8() {
+            *self = Self::try_from_iter(self.chars().map(|ch| if ch == from { to } else { ch }))?;
+            return Ok(());
+        }
+        let mut buf = [0; 4];
+        let to = to.encode_utf8(&mut buf).as_bytes();
+        let mut idx = 0;
+        while let Some(pos) = self.as_str()[idx..].find(from) {
+            idx += pos;
+            // Same length char for char, so still UTF-8 and padding untouched
+            self.str[idx..idx + to.len()].copy_from_slice(to);
+            idx += to.len();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(doctest)]
+mod doctests {
+    /**
+    ```compile_fail
+    let mut slet = stringlet::stringlet!("abc");
+    slet.clear();
+    ```
+    */
+    fn fixed_clear_compile_fail() {}
+
+    /**
+    ```compile_fail
+    let mut slet = stringlet::stringlet!(trim: "abc");
+    _ = slet.push('d');
+    ```
+    */
+    fn trim_push_compile_fail() {}
+
+    /**
+    ```compile_fail
+    let _: stringlet::Stringlet<4> = stringlet::stringlet!(var 4: "abc").widen();
+    ```
+    */
+    fn var_widen_fixed_compile_fail() {}
+
+    /**
+    ```compile_fail
+    let _: stringlet::VarStringlet<2> = stringlet::stringlet!("abc").widen();
+    ```
+    */
+    fn widen_smaller_compile_fail() {}
+
+    /**
+    ```compile_fail
+    let _: stringlet::VarStringlet<8> = stringlet::stringlet!(v 4: "abc").to_uppercase_widened();
+    ```
+    */
+    fn to_uppercase_widened_compile_fail() {}
+
+    /**
+    ```compile_fail
+    let _: stringlet::VarStringlet<5> = stringlet::stringlet!(v 4: "abc").to_lowercase_widened();
+    ```
+    */
+    fn to_lowercase_widened_compile_fail() {}
+}
@@ -2,10 +2,10 @@
 
 use crate::*;
 
-impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE>
-where
-    Self: Config<Kind, SIZE>,
-{
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
     /** Create an empty `Self`. Will panic if type can’t be empty, e.g. `Stringlet<1>` or  `TrimStringlet<2>` */
     #[inline(always)]
     #[must_use]
@@ -31,6 +31,105 @@ where
         }
     }
 
+    /**
+    Like `from_str()`, but cut at the last char boundary that fits. Fails only if that is too short.
+    ```
+    # use stringlet::{Stringlet, VarStringlet, Result};
+    assert_eq!(VarStringlet::<4>::from_str_truncating("Käse")?, "Käs");
+    assert_eq!(VarStringlet::<4>::from_str_truncating("Kä")?, "Kä");
+    assert!(Stringlet::<2>::from_str_truncating("Käse").is_err());
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_str_truncating(str: &str) -> Result<Self> {
+        let mut len = if str.len() > SIZE { SIZE } else { str.len() };
+        while !str.is_char_boundary(len) {
+            len -= 1;
+        }
+        Self::from_str(str.split_at(len).0)
+    }
+
+    /**
+    Fill up to `SIZE` bytes, aligning `str` within `fill` like `format!()` does. Fails if `str` is too long. A multibyte
+    `fill` that doesn’t add up to `SIZE` fills as many whole chars as fit, so this fails only if the result is too
+    short for `Self`. Use [`trim_padding()`](Self::trim_padding) to get `str` back.
+    ```
+    # use stringlet::{Align, Stringlet, VarStringlet, Result};
+    assert_eq!(Stringlet::<5>::from_str_padded("ab", ' ', Align::Left)?, "ab   ");
+    assert_eq!(Stringlet::<5>::from_str_padded("ab", '.', Align::Right)?, "...ab");
+    assert_eq!(Stringlet::<5>::from_str_padded("ab", '*', Align::Center)?, "*ab**");
+    assert_eq!(VarStringlet::<6>::from_str_padded("ab", '€', Align::Left)?, "ab€");
+    assert!(Stringlet::<6>::from_str_padded("ab", '€', Align::Left).is_err());
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_str_padded(str: &str, fill: char, align: Align) -> Result<Self> {
+        let len = str.len();
+        if len > SIZE {
+            return Err(Self::too_long(len));
+        }
+        let mut fill_buf = [0; 4];
+        let fill = fill.encode_utf8(&mut fill_buf).as_bytes();
+        let count = (SIZE - len) / fill.len();
+        let total = len + count * fill.len();
+        // const equivalent of `Self::fits(total)?`
+        if let Err(e) = Self::fits(total) {
+            return Err(e);
+        }
+        // Same as format!(), which puts the odd one after
+        let before = match align {
+            Align::Left => 0,
+            Align::Right => count,
+            Align::Center => count / 2,
+        };
+        let mut buf = [0; SIZE];
+        let mut i = 0;
+        while i < count {
+            let at = if i < before {
+                i * fill.len()
+            } else {
+                len + i * fill.len()
+            };
+            buf.split_at_mut(at)
+                .1
+                .split_at_mut(fill.len())
+                .0
+                .copy_from_slice(fill);
+            i += 1;
+        }
+        buf.split_at_mut(before * fill.len())
+            .1
+            .split_at_mut(len)
+            .0
+            .copy_from_slice(str.as_bytes());
+        // SAFETY whole chars, with a length that fits
+        Ok(unsafe { Self::from_utf8_unchecked(buf.split_at(total).0) })
+    }
+
+    /**
+    Right align a number in zeros, keeping a leading sign in front. Nothing is checked for being a number, so a sign
+    without digits comes out as a signed zero, which parses as `0`, if there is room for a digit.
+    ```
+    # use stringlet::{Stringlet, Result};
+    assert_eq!(Stringlet::<5>::from_str_zero_padded("42")?, "00042");
+    assert_eq!(Stringlet::<5>::from_str_zero_padded("-42")?, "-0042");
+    assert_eq!(Stringlet::<5>::from_str_zero_padded("-")?, "-0000");
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_str_zero_padded(str: &str) -> Result<Self> {
+        match Self::from_str_padded(str, '0', Align::Right) {
+            Ok(mut slet) => {
+                if let [sign @ (b'+' | b'-'), ..] = str.as_bytes() {
+                    slet.str[SIZE - str.len()] = b'0';
+                    slet.str[0] = *sign;
+                }
+                Ok(slet)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     /// # Safety
     /// It is the callers responsibility to ensure that the size fits
     #[must_use]
@@ -64,6 +163,104 @@ where
         }
     }
 
+    /**
+    Like [`String::from_utf8_lossy`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy),
+    replacing invalid sequences with `�` U+FFFD. Then like `from_str_truncating()`, cut at the last char boundary
+    that fits. Fails only if that is too short.
+    ```
+    # use stringlet::{VarStringlet, Result};
+    assert_eq!(VarStringlet::<8>::from_utf8_lossy(b"a\xFFb\xE2\x82")?, "a�b�");
+    assert_eq!(VarStringlet::<4>::from_utf8_lossy(b"a\xFFb")?, "a�");
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_utf8_lossy(str: &[u8]) -> Result<Self> {
+        let mut buf = [0; SIZE];
+        let mut len = 0;
+        let mut rest = str;
+        loop {
+            let (valid, error_len) = match str::from_utf8(rest) {
+                Ok(valid) => (valid, None),
+                // SAFETY checked up to there
+                Err(e) => (
+                    unsafe { str::from_utf8_unchecked(rest.split_at(e.valid_up_to()).0) },
+                    Some(e.error_len()),
+                ),
+            };
+            if !Self::append(&mut buf, &mut len, valid) {
+                break;
+            }
+            match error_len {
+                None => break,
+                Some(error_len) => {
+                    if !Self::append(&mut buf, &mut len, "\u{FFFD}") {
+                        break;
+                    }
+                    match error_len {
+                        Some(error_len) => rest = rest.split_at(valid.len() + error_len).1,
+                        // incomplete sequence at the end
+                        None => break,
+                    }
+                }
+            }
+        }
+        match Self::fits(len) {
+            // SAFETY we checked the length and only appended UTF-8
+            Ok(()) => Ok(unsafe { Self::from_utf8_unchecked(buf.split_at(len).0) }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /**
+    Concatenate `parts`, failing if the total doesn’t fit. In `const` context this is also produced by
+    [`stringlet!(concat: …)`](stringlet!()). At runtime there is also [`concat()`](crate::concat()).
+    ```
+    # use stringlet::{VarStringlet, Result, stringlet};
+    const REGION: VarStringlet<4> = stringlet!(v 4: "EU");
+    let key = VarStringlet::<8>::from_concat(&[REGION.as_str(), "-", "FR"])?;
+    assert_eq!(key, "EU-FR");
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_concat(parts: &[&str]) -> Result<Self> {
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            len += parts[i].len();
+            i += 1;
+        }
+        if let Err(e) = Self::fits(len) {
+            return Err(e);
+        }
+        let mut buf = [0; SIZE];
+        len = 0;
+        i = 0;
+        while i < parts.len() {
+            Self::append(&mut buf, &mut len, parts[i]);
+            i += 1;
+        }
+        // SAFETY we checked the length and only appended UTF-8
+        Ok(unsafe { Self::from_utf8_unchecked(buf.split_at(len).0) })
+    }
+
+    /// Copy as much of `str` behind `len` as fits at a char boundary, returning whether all of it fit.
+    const fn append(buf: &mut [u8; SIZE], len: &mut usize, str: &str) -> bool {
+        let mut end = str.len();
+        if end > SIZE - *len {
+            end = SIZE - *len;
+            while !str.is_char_boundary(end) {
+                end -= 1;
+            }
+        }
+        buf.split_at_mut(*len)
+            .1
+            .split_at_mut(end)
+            .0
+            .copy_from_slice(str.as_bytes().split_at(end).0);
+        *len += end;
+        end == str.len()
+    }
+
     /**
     ```
     # use stringlet::Stringlet;
@@ -75,6 +272,7 @@ where
     It is the callers responsibility to ensure that the size fits and the content is UTF-8. */
     #[must_use]
     pub const unsafe fn from_utf8_unchecked(str: &[u8]) -> Self {
+        Self::assert_size();
         let bytes_len = str.len();
 
         /* let mut str_uninit = core::mem::MaybeUninit::uninit();
@@ -126,6 +324,7 @@ where
     It is the callers responsibility to ensure that the content is UTF-8. */
     #[must_use]
     pub const unsafe fn from_utf8_bytes_unchecked(str: [u8; SIZE]) -> Self {
+        Self::assert_size();
         let mut me_uninit = core::mem::MaybeUninit::<Self>::uninit();
         let me = me_uninit.as_mut_ptr() as *mut u8;
         // SAFETY we write to whole uninit via pointer methods only before Rust sees the value
@@ -173,6 +372,47 @@ where
         unsafe { Self::from_utf8_unchecked(str) }
     }
 
+    /**
+    Format into `Self`, as an alternative to `write!()`, which also works with the fixed kinds. Fails if the output
+    doesn’t fit. This is also produced by [`format_stringlet!(…)`](format_stringlet!()).
+    ```
+    # use stringlet::{Stringlet, Result};
+    let key = Stringlet::<7>::from_fmt(format_args!("{}-{:03}", "EUR", 7))?;
+    assert_eq!(key, "EUR-007");
+    # Result::Ok(())
+    ```
+    */
+    pub fn from_fmt(args: core::fmt::Arguments<'_>) -> Result<Self> {
+        Self::from_buffer(args, false)
+    }
+
+    /**
+    Format into `Self`, cutting the output at the last char boundary that fits. Fails only if that is too short. This
+    is also produced by [`format_stringlet!(truncate …)`](format_stringlet!()).
+    ```
+    # use stringlet::{VarStringlet, Result};
+    let key = VarStringlet::<5>::from_fmt_truncating(format_args!("{}-{:03}", "EUR", 7))?;
+    assert_eq!(key, "EUR-0");
+    # Result::Ok(())
+    ```
+    */
+    pub fn from_fmt_truncating(args: core::fmt::Arguments<'_>) -> Result<Self> {
+        Self::from_buffer(args, true)
+    }
+
+    fn from_buffer(args: core::fmt::Arguments<'_>, truncate: bool) -> Result<Self> {
+        let mut buffer = crate::fmt::Buffer::<SIZE>::new(truncate);
+        let result = core::fmt::write(&mut buffer, args);
+        if buffer.overflow > 0 {
+            return Err(Self::too_long(buffer.len + buffer.overflow));
+        } else if let Err(e) = result
+            && !buffer.truncated
+        {
+            return Err(FmtError(e));
+        }
+        Self::from_str(buffer.as_str())
+    }
+
     // With specialization the type system could check Kind == Kind2 and SIZE == SIZE2 to avoid the extra check.
     pub const fn from_stringlet<Kind2: crate::Kind, const SIZE2: usize>(
         str: self2!(),
@@ -206,15 +446,57 @@ where
         match Self::fits(str.len()) {
             // SAFETY we checked the length and got UTF-8
             Ok(()) => unsafe { Self::from_str_unchecked(str) },
-            Err(TooLong) => panic!("stringlet!(...): parameter too long for its type."),
-            Err(TooShort) => panic!("stringlet!(...): parameter too short for its type."),
-            Err(Utf8Error(_)) => unreachable!(),
+            Err(e) => panic!("{}", e.message(error::MACRO_PREFIX).as_str()),
+        }
+    }
+
+    /// Fail to compile for an excessive `SIZE`. All constructors go through this, so an invalid type may be named but
+    /// never instantiated.
+    #[inline(always)]
+    pub(crate) const fn assert_size() {
+        const {
+            assert!(
+                !Kind::VAR || SIZE <= 255,
+                "VarStringlet cannot be longer than 255 bytes. Consider using String!"
+            );
+            assert!(
+                !Kind::SLIM || SIZE <= 64,
+                "SlimStringlet cannot be longer than 64 bytes. Consider using VarStringlet!"
+            );
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    pub const fn _from_concat_macro(parts: &[&str]) -> Self {
+        match Self::from_concat(parts) {
+            Ok(slet) => slet,
+            Err(e) => panic!("{}", e.message(error::CONCAT_PREFIX).as_str()),
+        }
+    }
+
+    pub(crate) const fn too_long(len: usize) -> error::Error {
+        TooLong {
+            len,
+            min: Self::MIN_LEN,
+            max: SIZE,
+            kind: Kind::NAME,
+        }
+    }
+
+    pub(crate) const fn too_short(len: usize) -> error::Error {
+        TooShort {
+            len,
+            min: Self::MIN_LEN,
+            max: SIZE,
+            kind: Kind::NAME,
         }
     }
 
     pub(crate) const fn fits(len: usize) -> Result<()> {
         if len > SIZE {
-            Err(TooLong)
+            Err(Self::too_long(len))
         } else if (Kind::FIXED && len == SIZE)
             || Kind::VAR
             || Kind::SLIM
@@ -222,13 +504,13 @@ where
         {
             Ok(())
         } else {
-            Err(TooShort)
+            Err(Self::too_short(len))
         }
     }
 }
 
 impl_for! {
-    <Config> Default:
+    Default:
 
     fn default() -> Self {
         Self::new()
@@ -236,7 +518,7 @@ impl_for! {
 }
 
 /* impl_for! {
-    <Config, 2> TryFrom<self2!()>:
+    <2> TryFrom<self2!()>:
 
     type Error = error::Error;
 
@@ -245,8 +527,9 @@ impl_for! {
     }
 } */
 
+#[cfg(feature = "alloc")]
 impl_for! {
-    <Config> TryFrom<String>:
+    TryFrom<String>:
 
     type Error = error::Error;
 
@@ -256,7 +539,7 @@ impl_for! {
 }
 
 impl_for! {
-    <Config> TryFrom<&str>:
+    TryFrom<&str>:
 
     type Error = error::Error;
 
@@ -265,6 +548,64 @@ impl_for! {
     }
 }
 
+/// What [`try_from_iter()`](StringletBase::try_from_iter) and [`try_extend()`](StringletBase::try_extend) take from
+/// an iterator: `char`, `&char` or `&str`. This is also what [`split_into()`](StringletBase::split_into),
+/// [`replace()`](StringletBase::replace) and their siblings look for. There an empty `&str` matches between all chars
+/// and at both ends, like for `str`.
+pub trait StrPiece {
+    /// Get `self` as `str`, encoding into `buf` if needed.
+    fn as_str<'a>(&'a self, buf: &'a mut [u8; 4]) -> &'a str;
+}
+
+impl StrPiece for char {
+    #[inline]
+    fn as_str<'a>(&'a self, buf: &'a mut [u8; 4]) -> &'a str {
+        self.encode_utf8(buf)
+    }
+}
+
+impl StrPiece for &char {
+    #[inline]
+    fn as_str<'a>(&'a self, buf: &'a mut [u8; 4]) -> &'a str {
+        self.encode_utf8(buf)
+    }
+}
+
+impl StrPiece for &str {
+    #[inline]
+    fn as_str<'a>(&'a self, _buf: &'a mut [u8; 4]) -> &'a str {
+        self
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    Collect `char`s or `&str`s without allocating. Fails as soon as a piece doesn’t fit, in which case the error’s
+    `len` counts only up to that piece. Or at the end, if the fixed kinds come out too short.
+    ```
+    # use stringlet::{Stringlet, VarStringlet, Result};
+    let digits = VarStringlet::<8>::try_from_iter("2025-12-31".chars().filter(char::is_ascii_digit))?;
+    assert_eq!(digits, "20251231");
+    let words = Stringlet::<6>::try_from_iter(["ab", "cd", "ef"])?;
+    assert_eq!(words, "abcdef");
+    assert!(VarStringlet::<4>::try_from_iter(["ab", "cd", "ef"]).is_err());
+    # Result::Ok(())
+    ```
+    */
+    pub fn try_from_iter<P: StrPiece>(iter: impl IntoIterator<Item = P>) -> Result<Self> {
+        use core::fmt::Write;
+        let mut buffer = crate::fmt::Buffer::<SIZE>::new(false);
+        for piece in iter {
+            // Buffer only counts on overflow
+            _ = buffer.write_str(piece.as_str(&mut [0; 4]));
+            if buffer.overflow > 0 {
+                return Err(Self::too_long(buffer.len + buffer.overflow));
+            }
+        }
+        Self::from_str(buffer.as_str())
+    }
+}
+
 #[cfg(doctest)]
 mod doctests {
     /**
@@ -329,6 +670,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::nonminimal_bool)] // lines up !is_ok() with is_ok()
     fn fits() {
         assert!(Stringlet::<0>::fits(0).is_ok());
         assert!(Stringlet::<0>::fits(1).is_err());
@@ -0,0 +1,71 @@
+//! Concatenation, also with `+`
+
+use crate::*;
+
+use core::ops::{Add, AddAssign};
+
+/**
+Concatenate any two stringlets or `str`s into `Target`, failing if the result doesn’t fit. For more parts, or in
+`const` context, there is [`StringletBase::from_concat()`].
+```
+# use stringlet::{concat, stringlet, Result, SlimStringlet, VarStringlet};
+let key: VarStringlet<8> = concat(stringlet!("EU-"), &stringlet!(slim 4: "FR"))?;
+assert_eq!(key, "EU-FR");
+assert_eq!(concat::<SlimStringlet<6>>(key, "!")?, "EU-FR!");
+# Result::Ok(())
+```
+*/
+pub fn concat<Target: AnyStringlet>(a: impl AsRef<str>, b: impl AsRef<str>) -> Result<Target> {
+    Target::from_concat(&[a.as_ref(), b.as_ref()])
+}
+
+/// `+` and `+=` for `VarStringlet` and `SlimStringlet`, like for `String`. As they can’t grow, `+` fails like
+/// `push_str()` if the result doesn’t fit, whereas `+=` panics.
+macro_rules! impl_add {
+    ($($stringlet:ident)+) => {
+        $(
+            impl<const SIZE: usize> Add<&str> for $stringlet<SIZE> {
+                type Output = Result<Self>;
+
+                #[inline]
+                fn add(mut self, str: &str) -> Result<Self> {
+                    self.push_str(str)?;
+                    Ok(self)
+                }
+            }
+
+            impl<Kind2: crate::Kind, const SIZE: usize, const SIZE2: usize> Add<&self2!()> for $stringlet<SIZE> {
+                type Output = Result<Self>;
+
+                #[inline]
+                fn add(self, slet: &self2!()) -> Result<Self> {
+                    self + slet.as_str()
+                }
+            }
+
+            impl<const SIZE: usize> AddAssign<&str> for $stringlet<SIZE> {
+                /// # Panics
+                /// If the result doesn’t fit, like `String` beyond its maximum capacity. Use `push_str()` to handle
+                /// that.
+                #[inline]
+                fn add_assign(&mut self, str: &str) {
+                    if let Err(e) = self.push_str(str) {
+                        panic!("{e}");
+                    }
+                }
+            }
+
+            impl<Kind2: crate::Kind, const SIZE: usize, const SIZE2: usize> AddAssign<&self2!()> for $stringlet<SIZE> {
+                /// # Panics
+                /// If the result doesn’t fit, like `String` beyond its maximum capacity. Use `push_str()` to handle
+                /// that.
+                #[inline]
+                fn add_assign(&mut self, slet: &self2!()) {
+                    *self += slet.as_str();
+                }
+            }
+        )+
+    };
+}
+
+impl_add!(VarStringlet SlimStringlet);
@@ -0,0 +1,219 @@
+//! `OptionStringletBase`, an `Option` without extra space, using a UTF-8 niche.
+
+use crate::*;
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
+
+/**
+According to the UTF-8 standard no byte can be `0b1111_1xxx`. Nor can it be our padding, as the `TAG`ged byte is only
+ever first for size 1, where it’s at most `TAG | 1`. So this 1<sup>st</sup> byte means `None`.
+*/
+const NONE: u8 = 0xFF;
+
+/** Like `Option<StringletBase<Kind, SIZE>>`, but without the extra discriminant byte, and its padding.
+This is the underlying type, which you would not use directly. Instead use one of [`OptionStringlet`],
+[`OptionVarStringlet`], [`OptionTrimStringlet`], or [`OptionSlimStringlet`]. As it needs the 1<sup>st</sup> byte,
+`SIZE` must not be 0.
+```
+# use stringlet::{OptionStringlet, Stringlet, stringlet};
+let mut code = OptionStringlet::<3>::NONE;
+assert_eq!(size_of_val(&code), size_of::<Stringlet<3>>());
+assert!(code.is_none());
+assert_eq!(code.replace(stringlet!("EUR")), None);
+assert_eq!(code.as_ref(), Some(&stringlet!("EUR")));
+assert_eq!(code.take(), Some(stringlet!("EUR")));
+assert!(code.is_none());
+```
+*/
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct OptionStringletBase<Kind: crate::Kind, const SIZE: usize> {
+    /// Only a valid stringlet if the 1<sup>st</sup> byte isn’t `NONE`.
+    slet: StringletBase<Kind, SIZE>,
+}
+
+macro_rules! option {
+    ($($option:ident $stringlet:ident $kind:ident),+) => {
+        $(
+            #[doc = concat!("An optional [`", stringify!($stringlet), "`], which is no bigger.")]
+            pub type $option<const SIZE: usize = 16> = OptionStringletBase<$kind, SIZE>;
+        )+
+    };
+}
+
+option!(
+    OptionStringlet Stringlet Fixed,
+    OptionVarStringlet VarStringlet Var,
+    OptionTrimStringlet TrimStringlet Trim,
+    OptionSlimStringlet SlimStringlet Slim
+);
+
+impl<Kind: crate::Kind, const SIZE: usize> OptionStringletBase<Kind, SIZE> {
+    pub const NONE: Self = {
+        assert!(SIZE > 0, "a zero size stringlet has no niche for None");
+        StringletBase::<Kind, SIZE>::assert_size();
+        let mut none_uninit = core::mem::MaybeUninit::<Self>::uninit();
+        let none = none_uninit.as_mut_ptr() as *mut u8;
+        // SAFETY we write to whole uninit via pointer methods only before Rust sees the value
+        unsafe {
+            none.write_bytes(0, size_of::<Self>());
+            none.write(NONE);
+            none_uninit.assume_init()
+        }
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn none() -> Self {
+        Self::NONE
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn some(slet: StringletBase<Kind, SIZE>) -> Self {
+        const { assert!(SIZE > 0, "a zero size stringlet has no niche for None") }
+        Self { slet }
+    }
+
+    #[inline]
+    pub const fn is_some(&self) -> bool {
+        self.slet.str[0] != NONE
+    }
+
+    #[inline]
+    pub const fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    #[inline]
+    pub const fn as_ref(&self) -> Option<&StringletBase<Kind, SIZE>> {
+        if self.is_some() {
+            Some(&self.slet)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn as_mut(&mut self) -> Option<&mut StringletBase<Kind, SIZE>> {
+        if self.is_some() {
+            Some(&mut self.slet)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn into_option(self) -> Option<StringletBase<Kind, SIZE>> {
+        if self.is_some() {
+            Some(self.slet)
+        } else {
+            None
+        }
+    }
+
+    /// Take the value out, leaving `NONE`.
+    #[inline]
+    pub const fn take(&mut self) -> Option<StringletBase<Kind, SIZE>> {
+        core::mem::replace(self, Self::NONE).into_option()
+    }
+
+    /// Put in a value, returning the old one.
+    #[inline]
+    pub const fn replace(
+        &mut self,
+        slet: StringletBase<Kind, SIZE>,
+    ) -> Option<StringletBase<Kind, SIZE>> {
+        core::mem::replace(self, Self::some(slet)).into_option()
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Default for OptionStringletBase<Kind, SIZE> {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> From<StringletBase<Kind, SIZE>>
+    for OptionStringletBase<Kind, SIZE>
+{
+    fn from(slet: StringletBase<Kind, SIZE>) -> Self {
+        Self::some(slet)
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> From<Option<StringletBase<Kind, SIZE>>>
+    for OptionStringletBase<Kind, SIZE>
+{
+    fn from(option: Option<StringletBase<Kind, SIZE>>) -> Self {
+        match option {
+            Some(slet) => Self::some(slet),
+            None => Self::NONE,
+        }
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> From<OptionStringletBase<Kind, SIZE>>
+    for Option<StringletBase<Kind, SIZE>>
+{
+    fn from(option: OptionStringletBase<Kind, SIZE>) -> Self {
+        option.into_option()
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Debug for OptionStringletBase<Kind, SIZE> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        self.as_ref().fmt(fmt)
+    }
+}
+
+// As `NONE` is always the same, raw comparison works just like for stringlets.
+
+impl<Kind: crate::Kind, const SIZE: usize> Eq for OptionStringletBase<Kind, SIZE> {}
+
+impl<Kind: crate::Kind, const SIZE: usize> PartialEq for OptionStringletBase<Kind, SIZE> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.slet.as_slice() == other.slet.as_slice()
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> PartialOrd for OptionStringletBase<Kind, SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Ord for OptionStringletBase<Kind, SIZE> {
+    /// Like `Option`, `None` is less than anything.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(&other.as_ref())
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> Hash for OptionStringletBase<Kind, SIZE> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state);
+    }
+}
+
+#[cfg(doctest)]
+mod doctests {
+    /**
+    ```compile_fail
+    _ = stringlet::OptionStringlet::<0>::NONE;
+    ```
+    */
+    fn option_stringlet_0_none_compile_fail() {}
+
+    /**
+    ```compile_fail
+    _ = stringlet::OptionVarStringlet::<0>::some(stringlet::VarStringlet::<0>::new());
+    ```
+    */
+    fn option_var_stringlet_0_some_compile_fail() {}
+}
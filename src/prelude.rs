@@ -1,2 +1,7 @@
+#[cfg(feature = "alloc")]
+pub use crate::SpillStringlet;
 #[allow(unused_imports)]
-pub use crate::{SlimStringlet, Stringlet, TrimStringlet, VarStringlet, stringlet};
+pub use crate::{
+    AnyStringlet, AutoStringlet, SlimStringlet, Stringlet, TrimStringlet, VarStringlet,
+    format_stringlet, stringlet,
+};
@@ -2,6 +2,9 @@
 
 use crate::*;
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String};
+use core::borrow::Borrow;
 use core::ops::Deref;
 
 impl_for! {
@@ -24,40 +27,148 @@ impl_for! {
     }
 }
 
+// Sound, because `Hash`, `Eq` and `Ord` are the same as for `str`.
+impl_for! {
+    Borrow<str>:
+
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
+    /**
+    Cast a `str` as a shared reference to `Self`. This is only offered where the layout really matches, i.e. not for
+    `VarStringlet`, which would need a length byte after the `str`. The size may be inferred, but it must match the
+    input length! For `TrimStringlet` and `SlimStringlet` this means full length, which is never misread as tagged, as
+    the last byte of valid UTF-8 is always less than the `TAG`.
+    ```
+    # use stringlet::{Stringlet, SlimStringlet, Result};
+    let str = "Hello Rust!";
+    let hello: &Stringlet<5> = Stringlet::from_str_ref(&str[..5])?;
+    let rust = SlimStringlet::<5>::from_str_ref(&str[6..])?;
+    assert_eq!(hello, "Hello");
+    assert_eq!(rust.len(), 5);
+    assert!(SlimStringlet::<5>::from_str_ref(&str[..4]).is_err());
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_str_ref(str: &str) -> Result<&Self> {
+        Self::assert_castable();
+        match Self::fits_exactly(str.len()) {
+            // SAFETY Self is a repr(C) [u8; SIZE] with alignment 1, and we checked the length
+            Ok(()) => Ok(unsafe { &*(str.as_ptr() as *const Self) }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /**
+    Cast a `str` as a mutable reference to `Self`. This is only offered for `Stringlet`, as other kinds could write
+    their padding into the `str`, making it invalid UTF-8. The size may be inferred, but it must match the input
+    length!
+    ```
+    # use stringlet::{Stringlet, Result, stringlet};
+    let mut string = String::from("eur/usd");
+    *Stringlet::<3>::from_str_mut(&mut string[4..])? = stringlet!("USD");
+    assert_eq!(string, "eur/USD");
+    # Result::Ok(())
+    ```
+    */
+    pub const fn from_str_mut(str: &mut str) -> Result<&mut Self> {
+        const { assert!(Kind::FIXED, "only Stringlet can be cast from &mut str") }
+        Self::assert_castable();
+        match Self::fits_exactly(str.len()) {
+            // SAFETY Self is a repr(C) [u8; SIZE] with alignment 1, and we checked the length
+            Ok(()) => Ok(unsafe { &mut *(str.as_mut_ptr() as *mut Self) }),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline(always)]
+    const fn assert_castable() {
+        Self::assert_size();
+        const {
+            assert!(
+                size_of::<Self>() == SIZE && align_of::<Self>() == 1,
+                "VarStringlet can’t be cast from &str"
+            );
+        }
+    }
+
+    const fn fits_exactly(len: usize) -> Result<()> {
+        if len > SIZE {
+            Err(TooLong {
+                len,
+                min: SIZE,
+                max: SIZE,
+                kind: Kind::NAME,
+            })
+        } else if len < SIZE {
+            Err(TooShort {
+                len,
+                min: SIZE,
+                max: SIZE,
+                kind: Kind::NAME,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The panicking convenience version of `from_str_ref()`.
 macro_rules! impl_ref {
-    ($type:ty => $stringlet:ident $(: $conf:tt)?) => {
-        impl<const SIZE: usize> AsRef<$stringlet<SIZE>> for $type
-        $(where
-            $stringlet<SIZE>: $conf<SIZE>)?
-        {
+    ($type:ty => $stringlet:ident) => {
+        impl<const SIZE: usize> AsRef<$stringlet<SIZE>> for $type {
             #[doc = concat!("Cast a `", stringify!($type), "` as a shared reference to a [`", stringify!($stringlet), "`].")]
             /// The size may be inferred, but it must match the input length!
             #[inline]
             fn as_ref(&self) -> &$stringlet<SIZE> {
-                assert_eq!(
-                    self.len(),
-                    SIZE,
-                    concat!("Cannot cast a len {} ", stringify!($type), " as &", stringify!($stringlet), "<SIZE>"),
-                    self.len()
-                );
-                // SAFETY I’m not sure. It seems to cast the input bytes to stringlet just fine.
-                unsafe { core::mem::transmute(&*(self.as_ptr() as *const $stringlet<SIZE>)) }
+                match $stringlet::from_str_ref(self) {
+                    Ok(slet) => slet,
+                    Err(_) => panic!(
+                        concat!("Cannot cast a len {} ", stringify!($type), " as &", stringify!($stringlet), "<{}>"),
+                        self.len(),
+                        SIZE
+                    ),
+                }
             }
         }
     };
 }
 
 impl_ref!(str => Stringlet);
-impl_ref!(str => VarStringlet: VarConfig);
 impl_ref!(str => TrimStringlet);
-impl_ref!(str => SlimStringlet: SlimConfig);
+impl_ref!(str => SlimStringlet);
 
+#[cfg(feature = "alloc")]
 impl_ref!(String => Stringlet);
-impl_ref!(String => VarStringlet: VarConfig);
+#[cfg(feature = "alloc")]
 impl_ref!(String => TrimStringlet);
-impl_ref!(String => SlimStringlet: SlimConfig);
+#[cfg(feature = "alloc")]
+impl_ref!(String => SlimStringlet);
 
+#[cfg(feature = "alloc")]
 impl_ref!(Box<str> => Stringlet);
-impl_ref!(Box<str> => VarStringlet: VarConfig);
+#[cfg(feature = "alloc")]
 impl_ref!(Box<str> => TrimStringlet);
-impl_ref!(Box<str> => SlimStringlet: SlimConfig);
+#[cfg(feature = "alloc")]
+impl_ref!(Box<str> => SlimStringlet);
+
+#[cfg(doctest)]
+mod doctests {
+    /**
+    ```compile_fail
+    _ = stringlet::VarStringlet::<3>::from_str_ref("abc");
+    ```
+    */
+    fn var_stringlet_from_str_ref_compile_fail() {}
+
+    /**
+    ```compile_fail
+    _ = stringlet::SlimStringlet::<3>::from_str_mut(&mut String::from("abc"));
+    ```
+    */
+    fn slim_stringlet_from_str_mut_compile_fail() {}
+}
@@ -0,0 +1,50 @@
+//! Optional SerDe support: all kinds serialize as a plain `str`.
+
+use crate::*;
+
+use ::serde::de::{Deserialize, Deserializer, Error, Expected, Unexpected, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+use core::fmt::Formatter;
+
+impl_for! {
+    Serialize:
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl_for! {
+    <'de> Deserialize<'de>:
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        deserializer.deserialize_str(StringletVisitor(PhantomData))
+    }
+}
+
+/// Checks length and kind constraints through `from_str()`, and names the kind and size when they are violated.
+struct StringletVisitor<Kind, const SIZE: usize>(PhantomData<Kind>);
+
+impl<'de, Kind: crate::Kind, const SIZE: usize> Visitor<'de> for StringletVisitor<Kind, SIZE> {
+    type Value = StringletBase<Kind, SIZE>;
+
+    fn expecting(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        let (min, max) = (Self::Value::MIN_LEN, Self::Value::MAX_LEN);
+        write!(
+            fmt,
+            "{}<{SIZE}>, i.e. a str of {min}..={max} bytes",
+            Kind::NAME
+        )
+    }
+
+    fn visit_str<E: Error>(self, str: &str) -> core::result::Result<Self::Value, E> {
+        StringletBase::from_str(str).map_err(|_| E::invalid_length(str.len(), &self))
+    }
+
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> core::result::Result<Self::Value, E> {
+        StringletBase::from_utf8(bytes).map_err(|e| match e {
+            Utf8Error(_) => E::invalid_value(Unexpected::Bytes(bytes), &self as &dyn Expected),
+            _ => E::invalid_length(bytes.len(), &self),
+        })
+    }
+}
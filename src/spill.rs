@@ -0,0 +1,297 @@
+//! `SpillStringlet`, which stays inline until it overflows onto the heap.
+
+use crate::cmp::impl_reversed;
+use crate::*;
+
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter, Write};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/**
+A `Cow`-like pair of a storage-constrained `VarStringlet<SIZE>` and a limitless `String`. It stays inline, until a
+construction or a push exceeds `SIZE`. Only then does it transparently move to the heap. Unlike stringlets it never
+rejects content. `Eq`, `Ord` and `Hash` are the same as for `str`, no matter where the content is.
+```
+# use stringlet::SpillStringlet;
+let mut name = SpillStringlet::<8>::from("Daniel");
+assert!(name.is_inline());
+name.push_str(" Pfeiffer");
+assert!(!name.is_inline());
+assert_eq!(name, "Daniel Pfeiffer");
+```
+*/
+#[derive(Clone)]
+pub enum SpillStringlet<const SIZE: usize = 16> {
+    Inline(VarStringlet<SIZE>),
+    Heap(String),
+}
+
+impl<const SIZE: usize> SpillStringlet<SIZE> {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::Inline(VarStringlet::new())
+    }
+
+    /// Infallible, as what doesn’t fit inline goes to the heap.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)] // same name as in StringletBase
+    pub fn from_str(str: &str) -> Self {
+        match VarStringlet::from_str(str) {
+            Ok(slet) => Self::Inline(slet),
+            Err(_) => Self::Heap(str.into()),
+        }
+    }
+}
+
+impl<const SIZE: usize> SpillStringlet<SIZE> {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Inline(slet) => slet.as_str(),
+            Self::Heap(string) => string.as_str(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
+    /// Whether the content is still stored in place.
+    #[inline]
+    pub const fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline(_))
+    }
+
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Append `str`, first moving to the heap if it doesn’t fit inline.
+    pub fn push_str(&mut self, str: &str) {
+        match self {
+            Self::Inline(slet) => {
+                if slet.push_str(str).is_err() {
+                    let mut string = String::with_capacity(slet.len() + str.len());
+                    string.push_str(slet);
+                    string.push_str(str);
+                    *self = Self::Heap(string);
+                }
+            }
+            Self::Heap(string) => string.push_str(str),
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        match self {
+            Self::Inline(slet) => slet.pop(),
+            Self::Heap(string) => string.pop(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        match self {
+            Self::Inline(slet) => slet.clear(),
+            Self::Heap(string) => string.clear(),
+        }
+    }
+
+    #[must_use]
+    pub fn into_string(self) -> String {
+        match self {
+            Self::Inline(slet) => slet.as_str().into(),
+            Self::Heap(string) => string,
+        }
+    }
+}
+
+impl<const SIZE: usize> Default for SpillStringlet<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize> From<&str> for SpillStringlet<SIZE> {
+    fn from(str: &str) -> Self {
+        Self::from_str(str)
+    }
+}
+
+impl<const SIZE: usize> From<String> for SpillStringlet<SIZE> {
+    /// Goes inline if it fits, else keeps the `String`.
+    fn from(string: String) -> Self {
+        match VarStringlet::from_str(&string) {
+            Ok(slet) => Self::Inline(slet),
+            Err(_) => Self::Heap(string),
+        }
+    }
+}
+
+impl<const SIZE: usize> From<VarStringlet<SIZE>> for SpillStringlet<SIZE> {
+    fn from(slet: VarStringlet<SIZE>) -> Self {
+        Self::Inline(slet)
+    }
+}
+
+impl<const SIZE: usize> From<SpillStringlet<SIZE>> for String {
+    fn from(spill: SpillStringlet<SIZE>) -> Self {
+        spill.into_string()
+    }
+}
+
+impl<const SIZE: usize> Write for SpillStringlet<SIZE> {
+    fn write_str(&mut self, str: &str) -> core::fmt::Result {
+        self.push_str(str);
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize> Deref for SpillStringlet<SIZE> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const SIZE: usize> AsRef<str> for SpillStringlet<SIZE> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const SIZE: usize> Borrow<str> for SpillStringlet<SIZE> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const SIZE: usize> Display for SpillStringlet<SIZE> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), fmt)
+    }
+}
+
+impl<const SIZE: usize> Debug for SpillStringlet<SIZE> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Inline(slet) => write!(fmt, "Inline({slet:?})"),
+            Self::Heap(string) => write!(fmt, "Heap({string:?})"),
+        }
+    }
+}
+
+impl<const SIZE: usize> Eq for SpillStringlet<SIZE> {}
+
+impl<const SIZE: usize> PartialEq for SpillStringlet<SIZE> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Inline(a), Self::Inline(b)) => a == b,
+            _ => self.as_str() == other.as_str(),
+        }
+    }
+}
+
+impl<const SIZE: usize> PartialEq<str> for SpillStringlet<SIZE> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a, const SIZE: usize> PartialEq<&'a str> for SpillStringlet<SIZE> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const SIZE: usize> PartialEq<String> for SpillStringlet<SIZE> {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize, const SIZE2: usize> PartialEq<StringletBase<Kind, SIZE2>>
+    for SpillStringlet<SIZE>
+{
+    #[inline]
+    fn eq(&self, other: &StringletBase<Kind, SIZE2>) -> bool {
+        match self {
+            Self::Inline(slet) => slet == other,
+            Self::Heap(string) => other == string,
+        }
+    }
+}
+
+impl<const SIZE: usize> PartialOrd for SpillStringlet<SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const SIZE: usize> Ord for SpillStringlet<SIZE> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const SIZE: usize> PartialOrd<str> for SpillStringlet<SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+impl<'a, const SIZE: usize> PartialOrd<&'a str> for SpillStringlet<SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &&'a str) -> Option<Ordering> {
+        self.as_str().partial_cmp(*other)
+    }
+}
+
+impl<const SIZE: usize> PartialOrd<String> for SpillStringlet<SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<Kind: crate::Kind, const SIZE: usize, const SIZE2: usize>
+    PartialOrd<StringletBase<Kind, SIZE2>> for SpillStringlet<SIZE>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &StringletBase<Kind, SIZE2>) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl_reversed!(str => [const SIZE: usize] SpillStringlet<SIZE>);
+impl_reversed!(<'a> &'a str => [const SIZE: usize] SpillStringlet<SIZE>);
+impl_reversed!(String => [const SIZE: usize] SpillStringlet<SIZE>);
+impl_reversed!(
+    StringletBase<Kind, SIZE> => [Kind: crate::Kind, const SIZE: usize, const SIZE2: usize] SpillStringlet<SIZE2>
+);
+
+impl<const SIZE: usize> Hash for SpillStringlet<SIZE> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
@@ -2,12 +2,106 @@
 
 use crate::*;
 
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display};
 use core::hash::{Hash, Hasher};
+use core::ops::Deref;
 
+// Must be the same as for `str`, as that is what we `Borrow`. So we can’t hash the raw bytes including the padding.
 impl_for! {
     Hash:
 
+    #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.str.hash(state);
+        self.as_str().hash(state);
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl_for! { sealed::Sealed }
+
+/**
+Any kind and size of stringlet. This lets generic code take `impl AnyStringlet`, without restating the `Kind` and
+`SIZE` generics, or the bounds some kinds need.
+```
+# use stringlet::{stringlet, AnyStringlet};
+fn describe<S: AnyStringlet>(slet: &S) -> String {
+    format!("{} {:?} in {}..={}", S::NAME, slet.as_str(), S::MIN_LEN, S::MAX_LEN)
+}
+assert_eq!(describe(&stringlet!(trim 4: "abc")), r#"TrimStringlet "abc" in 3..=4"#);
+```
+This is sealed, i.e. it is only implemented by [`StringletBase`].
+*/
+pub trait AnyStringlet:
+    sealed::Sealed
+    + Deref<Target = str>
+    + AsRef<str>
+    + Borrow<str>
+    + Debug
+    + Display
+    + Eq
+    + Ord
+    + Hash
+    + Sized
+{
+    /// The capacity in bytes.
+    const SIZE: usize;
+    /// The shortest content this type can hold.
+    const MIN_LEN: usize;
+    /// The longest content this type can hold.
+    const MAX_LEN: usize;
+    /// Whether `MIN_LEN` is 0.
+    const CAN_BE_EMPTY: bool = Self::MIN_LEN == 0;
+    /// The name of this kind, e.g. `"VarStringlet"`.
+    const NAME: &'static str;
+    /// The first letter of this kind, e.g. `b'V'`.
+    const ABBR: u8;
+
+    fn from_str(str: &str) -> Result<Self>;
+
+    fn from_concat(parts: &[&str]) -> Result<Self>;
+
+    fn as_str(&self) -> &str;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool;
+}
+
+impl_for! {
+    AnyStringlet:
+
+    const SIZE: usize = SIZE;
+    const MIN_LEN: usize = Self::MIN_LEN;
+    const MAX_LEN: usize = Self::MAX_LEN;
+    const NAME: &'static str = Kind::NAME;
+    const ABBR: u8 = Kind::ABBR;
+
+    #[inline(always)]
+    fn from_str(str: &str) -> Result<Self> {
+        Self::from_str(str)
+    }
+
+    #[inline(always)]
+    fn from_concat(parts: &[&str]) -> Result<Self> {
+        Self::from_concat(parts)
+    }
+
+    #[inline(always)]
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
@@ -0,0 +1,25 @@
+//! Unicode properties `core` uses internally for `str::to_lowercase`, but doesn’t expose.
+
+/// Whether `ch` is Cased, i.e. Lowercase, Uppercase or a title case letter like `ǅ`, all of which change when lower
+/// casing.
+pub(crate) fn is_cased(ch: char) -> bool {
+    ch.is_lowercase() || ch.is_uppercase() || ch.to_lowercase().next() != Some(ch)
+}
+
+/// Whether `ch` is Case_Ignorable, like apostrophes, periods, combining marks and modifier letters.
+pub(crate) fn is_case_ignorable(ch: char) -> bool {
+    CASE_IGNORABLE
+        .binary_search_by(|&(first, last)| {
+            if last < ch {
+                core::cmp::Ordering::Less
+            } else if first > ch {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// Inclusive ranges of Case_Ignorable, derived by build.rs from this toolchain’s `str::to_lowercase`
+include!(concat!(env!("OUT_DIR"), "/case_ignorable.rs"));
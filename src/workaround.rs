@@ -13,6 +13,14 @@ impl<Kind: crate::Kind, const SIZE: usize> StringletBase<Kind, SIZE> {
         unsafe { (self as *const Self as *const u8).add(SIZE).read() }
     }
 
+    #[inline]
+    /// Workaround to set the extra len byte for `VarStringlet`.
+    pub(crate) const fn var_set_last(&mut self, len: u8) {
+        debug_assert!(Kind::VAR, "unchecked call");
+        // SAFETY: 1 byte after SIZE only used for VarStringlet
+        unsafe { (self as *mut Self as *mut u8).add(SIZE).write(len) }
+    }
+
     #[inline]
     /// Workaround for `&[u8; SIZE + Kind::EXTRA_LEN]`.
     pub(crate) const fn as_slice(&self) -> &[u8] {
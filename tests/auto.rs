@@ -0,0 +1,82 @@
+//! Test functionality of the `auto` module.
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use stringlet::prelude::*;
+
+#[test]
+fn cheapest() {
+    macro_rules! pick {
+        ($($str:expr => $variant:ident),+) => {
+            $(
+                let slet = AutoStringlet::from_str($str).unwrap();
+                assert!(matches!(slet, AutoStringlet::$variant(_)), "{slet:?}");
+                assert_eq!(slet.as_str(), $str);
+                assert_eq!(slet.len(), $str.len());
+            )+
+        };
+    }
+    pick! {
+        "" => Slim8,
+        "1234567" => Trim8,
+        "12345678" => Fixed8,
+        "123456789" => Slim16,
+        &"x".repeat(15) => Trim16,
+        &"x".repeat(16) => Fixed16,
+        &"x".repeat(17) => Slim32,
+        &"x".repeat(32) => Fixed32,
+        &"x".repeat(33) => Slim64,
+        &"x".repeat(63) => Trim64,
+        &"x".repeat(64) => Fixed64
+    }
+    assert_eq!(
+        AutoStringlet::from_str(&"x".repeat(65)),
+        Err(stringlet::error::Error::TooLong {
+            len: 65,
+            min: 0,
+            max: 64,
+            kind: "AutoStringlet"
+        })
+    );
+    assert_eq!(AutoStringlet::MAX_LEN, 64);
+}
+
+#[test]
+fn mixed() {
+    let all: [AutoStringlet; 5] = [
+        "abc".try_into().unwrap(),
+        AutoStringlet::from_stringlet(stringlet!("abc")).unwrap(),
+        AutoStringlet::from_stringlet(stringlet!(trim 4: "abc")).unwrap(),
+        stringlet!(var 32: "abc").into(),
+        stringlet!(slim 64: "abc").into(),
+    ];
+    let state = RandomState::new();
+    for a in all {
+        assert_eq!(a, "abc");
+        assert_eq!(state.hash_one(a), state.hash_one("abc"));
+        for b in all {
+            assert_eq!(a, b);
+        }
+    }
+    assert!(all[0] < AutoStringlet::from_str("abd").unwrap());
+    assert!(all[4] > "ab");
+
+    let mut map = HashMap::new();
+    map.insert(all[1], 1);
+    map.insert(all[4], 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&all[3]], 2);
+}
+
+#[test]
+fn traits() {
+    let slet = AutoStringlet::default();
+    assert!(slet.is_empty());
+    let slet = AutoStringlet::from(stringlet!(var 8: "abc"));
+    assert!(slet.contains('b'));
+    assert_eq!(format!("{slet}"), "abc");
+    assert_eq!(
+        format!("{slet:?}"),
+        "AutoStringlet(VarStringlet<8> { str: \"abc\" })"
+    );
+}
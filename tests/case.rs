@@ -0,0 +1,91 @@
+//! Test functionality of the `case` module.
+
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, RandomState};
+use stringlet::CaseInsensitive;
+use stringlet::prelude::*;
+
+macro_rules! check {
+    ($($kind:ident)+) => {
+        $(
+            let ci = |str: &str| $kind::<20>::try_from(str).map(CaseInsensitive);
+            let state = RandomState::new();
+            for (a, b, eq) in [
+                ("", "", true),
+                ("Content-Type", "content-TYPE", true),
+                ("Content-Type", "content-TYPE ", false),
+                ("Content-Type", "content_type", false),
+                ("@[`{", "@[`{", true),
+                ("@[`{", "`{@[", false),
+                ("Äbc", "äBC", false),
+                ("ÄBC", "Äbc", true),
+                ("0123456789abcdefXYZ", "0123456789ABCDEFxyz", true),
+                ("0123456789abcdefXYZ", "0123456789ABCDEFxy", false),
+                ("0123456789abcdefXYZ!", "0123456789ABCDEFxyz!", true),
+                ("0123456789abcdefXYZ!", "0123456789ABCDEFxyz", false),
+            ] {
+                // TrimStringlet can’t hold the short ones
+                let (Ok(a), Ok(b)) = (ci(a), ci(b)) else { continue };
+                assert_eq!(a == b, eq, "{a:?} {b:?}");
+                assert_eq!(a.cmp(&b).is_eq(), eq, "{a:?} {b:?}");
+                if eq {
+                    assert_eq!(state.hash_one(a), state.hash_one(b));
+                }
+            }
+        )+
+    };
+}
+
+#[test]
+fn eq_ord_hash() {
+    check!(VarStringlet TrimStringlet SlimStringlet);
+    // length byte 65 must not fold to 97
+    let a = CaseInsensitive(VarStringlet::<100>::try_from("a".repeat(65).as_str()).unwrap());
+    let b = CaseInsensitive(VarStringlet::<100>::try_from("A".repeat(97).as_str()).unwrap());
+    assert_ne!(a, b);
+    let a = CaseInsensitive(VarStringlet::<4>::try_from("ab\0").unwrap());
+    let b = CaseInsensitive(VarStringlet::<4>::try_from("AB").unwrap());
+    assert_ne!(a, b);
+    assert_eq!(
+        CaseInsensitive(stringlet!("HeLLo")),
+        CaseInsensitive(stringlet!("hello"))
+    );
+}
+
+#[test]
+fn ord() {
+    let ci = |str| CaseInsensitive(VarStringlet::<2>::try_from(str).unwrap());
+    let words = ["b", "B", "a", "_", "Ab", "aa", "Z"].map(ci);
+    // "b" and "B" collapse
+    let set: BTreeSet<_> = words.into_iter().collect();
+    assert_eq!(set.len(), 6);
+    assert!(set.into_iter().eq(["_", "A", "AA", "ab", "b", "z"].map(ci)));
+    // Stable, so "b" and "B" keep their order
+    let mut sorted = words;
+    sorted.sort();
+    assert_eq!(
+        sorted.map(CaseInsensitive::into_inner),
+        ["_", "a", "aa", "Ab", "b", "B", "Z"]
+    );
+}
+
+#[test]
+fn collections() {
+    let set = HashSet::from(
+        ["Accept", "Content-Type"]
+            .map(|str| CaseInsensitive(SlimStringlet::<16>::try_from(str).unwrap())),
+    );
+    assert!(set.contains(&CaseInsensitive(
+        SlimStringlet::try_from("CONTENT-type").unwrap()
+    )));
+    assert!(!set.contains(&CaseInsensitive(
+        SlimStringlet::try_from("Content").unwrap()
+    )));
+    let ci = CaseInsensitive::from(stringlet!("Abc"));
+    assert_eq!(ci.len(), 3);
+    assert_eq!(
+        format!("{ci}|{ci:?}"),
+        r#"Abc|CaseInsensitive(Stringlet<3> { str: "Abc" })"#
+    );
+    assert_eq!(ci.into_inner(), "Abc");
+}
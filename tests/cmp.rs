@@ -63,17 +63,17 @@ macro_rules! cmp_all {
     ($op:tt: $a:tt, $($rest:tt,)+) => {
         let a = stringlet!$a;
         assert_eq!(a $op a.clone(), a.as_str() $op a.as_str(), "{a:#?}");
-        //assert_eq!(a.as_str() $op a, a.as_str() $op a.as_str(), "{a:#?}");
+        assert_eq!(a.as_str() $op a, a.as_str() $op a.as_str(), "{a:#?}");
         assert_eq!(a $op a.as_str(), a.as_str() $op a.as_str(), "{a:#?}");
         let ac = const { stringlet!$a };
         assert_eq!(a $op ac, a.as_str() $op ac.as_str(), "{a:#?} {ac:#?}");
         $(
             let b = stringlet!$rest;
             assert_eq!(a $op b, a.as_str() $op b.as_str(), "{a:#?} {b:#?}");
-            //assert_eq!(a.as_str() $op b, a.as_str() $op b.as_str(), "{a:#?} {b:#?}");
+            assert_eq!(a.as_str() $op b, a.as_str() $op b.as_str(), "{a:#?} {b:#?}");
             assert_eq!(a $op b.as_str(), a.as_str() $op b.as_str(), "{a:#?} {b:#?}");
             assert_eq!(b $op a, b.as_str() $op a.as_str(), "{a:#?} {b:#?}");
-            //assert_eq!(b.as_str() $op a, b.as_str() $op a.as_str(), "{a:#?} {b:#?}");
+            assert_eq!(b.as_str() $op a, b.as_str() $op a.as_str(), "{a:#?} {b:#?}");
             assert_eq!(b $op a.as_str(), b.as_str() $op a.as_str(), "{a:#?} {b:#?}");
         )+
         cmp_all!($op: $($rest,)+);
@@ -88,19 +88,75 @@ cmp_all!(gt: >);
 cmp_all!(ge: >=);
 
 #[test]
+#[allow(clippy::op_ref)] // testing the impls for refs
 fn cmp_wrappers() {
     let slet = stringlet!("wow");
-    let string = String::from("wow");
     assert!(slet == "wow");
     assert!(slet >= "wow");
 
     assert!(slet == &slet);
     assert!(slet >= &slet);
     assert!(slet.cmp(&slet) == std::cmp::Ordering::Equal);
+}
 
+#[test]
+#[cfg(feature = "alloc")]
+#[allow(clippy::op_ref)] // testing the impls for refs
+fn cmp_string() {
+    let slet = stringlet!("wow");
+    let string = String::from("wow");
     assert!(slet == string);
     assert!(slet >= string);
 
     assert!(slet == &string);
     assert!(slet >= &string);
+
+    assert!(string == slet);
+    assert!(string <= slet);
+    assert!(&string == slet);
+    assert!(&string <= slet);
+
+    let cow = std::borrow::Cow::from("wow");
+    assert!(slet == cow);
+    assert!(cow == slet);
+    assert!(cow >= slet);
+    let boxed = Box::<str>::from("wox");
+    assert!(slet != boxed);
+    assert!(slet < boxed);
+    assert!(boxed > slet);
+}
+
+#[test]
+#[allow(clippy::op_ref)] // testing the impls for refs
+fn cmp_reversed() {
+    let slet = stringlet!(var 5: "wow");
+    assert!("wow" == slet);
+    assert!(*"wow" == slet);
+    assert!("wox" > slet);
+    assert!(*"wov" < slet);
+    assert!(b"wow"[..] == slet);
+    assert!(&b"wow"[..] == slet);
+    assert!(slet == b"wow"[..]);
+    assert!(slet == &b"wow"[..]);
+    assert!(slet < &b"wox"[..]);
+    assert!(&b"wox"[..] > slet);
+}
+
+#[test]
+fn cmp_char() {
+    macro_rules! cmp_char {
+        ($($a:tt)+) => {
+            $(
+                let a = stringlet!$a;
+                for ch in ['\0', 'w', 'ö', '€', '🦀'] {
+                    let str = ch.to_string();
+                    assert_eq!(a == ch, a == str.as_str(), "{a:?} {ch:?}");
+                    assert_eq!(ch == a, a == str.as_str(), "{a:?} {ch:?}");
+                    assert_eq!(a.partial_cmp(&ch), a.partial_cmp(str.as_str()), "{a:?} {ch:?}");
+                    assert_eq!(ch.partial_cmp(&a), str.as_str().partial_cmp(&a), "{a:?} {ch:?}");
+                }
+            )+
+        };
+    }
+    cmp_char!(("") ("w") (v 4: "w") (t 2: "w") (s 4: "w") ("ö") ("€") (s 4: "€") ("🦀") (v: "wow"));
 }
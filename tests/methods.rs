@@ -20,11 +20,7 @@ fn as_str() -> stringlet::Result<()> {
     Ok(())
 }
 
-fn all_lengths<const SIZE: usize>()
-where
-    VarStringlet<SIZE>: stringlet::VarConfig<SIZE>,
-    SlimStringlet<SIZE>: stringlet::SlimConfig<SIZE>,
-{
+fn all_lengths<const SIZE: usize>() {
     let str64s: [&str; 3] = [
         "0123456789_123456789_123456789_123456789_123456789_123456789_123",
         str::from_utf8(&[0; 64]).unwrap(),
@@ -95,3 +91,519 @@ fn try_into() {
     let x: Result<SlimStringlet<0>, _> = stringlet!("x").try_into();
     assert!(x.is_err());
 }
+
+#[test]
+fn ascii() {
+    macro_rules! check {
+        ($($kind:ident)+) => {
+            $(
+                let slet = $kind::<8>::try_from("aBc-Äö").unwrap();
+                assert!(!slet.is_ascii());
+                assert!(slet.eq_ignore_ascii_case("ABC-Äö"));
+                assert!(!slet.eq_ignore_ascii_case("ABC-äö"));
+                assert!(!slet.eq_ignore_ascii_case("ABC"));
+                // compare raw, including padding
+                assert_eq!(slet.to_ascii_uppercase(), $kind::<8>::try_from("ABC-Äö").unwrap());
+                assert_eq!(slet.to_ascii_lowercase(), $kind::<8>::try_from("abc-Äö").unwrap());
+                let mut slet = $kind::<8>::try_from("aBc-1").unwrap();
+                assert!(slet.is_ascii());
+                slet.make_ascii_uppercase();
+                assert_eq!(slet, $kind::<8>::try_from("ABC-1").unwrap());
+                slet.make_ascii_lowercase();
+                assert_eq!(slet, $kind::<8>::try_from("abc-1").unwrap());
+            )+
+        };
+    }
+    check!(VarStringlet SlimStringlet);
+    let trim = TrimStringlet::<8>::try_from("aBc-Äö").unwrap();
+    assert_eq!(
+        trim.to_ascii_uppercase(),
+        TrimStringlet::<8>::try_from("ABC-Äö").unwrap()
+    );
+    assert_eq!(stringlet!("aBc-Äö").to_ascii_lowercase(), "abc-Äö");
+
+    const CODES: [Stringlet<3>; 2] = [
+        stringlet!("eur").to_ascii_uppercase(),
+        stringlet!("Usd").to_ascii_uppercase(),
+    ];
+    assert_eq!(CODES, ["EUR", "USD"]);
+    const _: () = assert!(stringlet!(slim 4: "eUr").eq_ignore_ascii_case("EuR"));
+}
+
+#[test]
+fn case() {
+    for str in [
+        "",
+        "abc",
+        "Straße",
+        "ΐ",
+        "İstanbul",
+        "ὈΔΥΣΣΕΎΣ",
+        "K",
+        "ǆemal",
+        "ﬁx ǉ",
+    ] {
+        let slet = VarStringlet::<20>::try_from(str).unwrap();
+        let upper: VarStringlet<60> = slet.to_uppercase().unwrap();
+        assert_eq!(upper, str.to_uppercase().as_str());
+        assert_eq!(
+            slet.to_uppercase_widened::<stringlet::Var, 60>(),
+            str.to_uppercase().as_str()
+        );
+        let lower: SlimStringlet<30> = slet.to_lowercase().unwrap();
+        assert_eq!(lower, str.to_lowercase().as_str());
+        assert_eq!(
+            slet.to_lowercase_widened::<stringlet::Slim, 30>(),
+            str.to_lowercase().as_str()
+        );
+        assert_eq!(
+            slet.to_titlecase::<stringlet::Var, 60>().unwrap(),
+            slet.to_titlecase_widened::<stringlet::Var, 60>()
+        );
+    }
+    let slet = stringlet!(s 8: "ΐ");
+    assert!(slet.to_uppercase::<stringlet::Slim, 5>().is_err());
+    assert_eq!(
+        slet.to_uppercase::<stringlet::Fixed, 6>().unwrap(),
+        "\u{399}\u{308}\u{301}"
+    );
+    assert_eq!(
+        stringlet!("KΣ")
+            .to_lowercase::<stringlet::Fixed, 3>()
+            .unwrap(),
+        "kς"
+    );
+    assert!(
+        stringlet!("K")
+            .to_lowercase::<stringlet::Fixed, 3>()
+            .is_err()
+    );
+    // Final_Sigma skips case ignorable chars and only counts cased letters
+    for str in ["ΑΣ.Α", "中Σ", "Α.Σ", "ΑΣ'", "ΑΣ 中", "Σ"] {
+        let slet = VarStringlet::<20>::try_from(str).unwrap();
+        assert_eq!(
+            slet.to_lowercase::<stringlet::Var, 20>().unwrap(),
+            str.to_lowercase().as_str()
+        );
+    }
+    let lower: Stringlet<7> = stringlet!("ΑΣ.Α").to_lowercase().unwrap();
+    assert_eq!(lower, "ασ.α");
+    let lower: Stringlet<5> = stringlet!("中Σ").to_lowercase().unwrap();
+    assert_eq!(lower, "中σ");
+
+    for (str, title) in [
+        ("hello wORLD", "Hello World"),
+        ("o'neil-smith 3rd", "O'neil-Smith 3rd"),
+        ("ǆemal ǉubljana", "Ǆemal Ǉubljana"),
+        ("ﬁx  ß", "FIx  SS"),
+        ("ßa", "SSa"),
+        ("ᾳ", "ΑΙ"),
+    ] {
+        let slet = VarStringlet::<20>::try_from(str).unwrap();
+        assert_eq!(slet.to_titlecase::<stringlet::Var, 20>().unwrap(), title);
+    }
+}
+
+/// Chars before and after `Σ`, that are skipped, cased or neither, must decide Final_Sigma like `str`.
+#[test]
+fn final_sigma() {
+    let case_ignorable = "'.:^`·\u{ad}\u{300}\u{483}\u{200b}’\u{fe0f}\u{1f3fb}\u{e0001}ـ";
+    let also_cased = "ʰᵃ";
+    let cased = "aZßǅᾼⒶª\u{10400}";
+    let neither = " 1-中\u{a0}";
+    for ch in [case_ignorable, also_cased, cased, neither]
+        .iter()
+        .flat_map(|chars| chars.chars())
+    {
+        for str in [&['Α', ch, 'Σ'], &['Α', 'Σ', ch]] {
+            let str = String::from_iter(str);
+            let slet = VarStringlet::<12>::try_from(str.as_str()).unwrap();
+            assert_eq!(
+                slet.to_lowercase::<stringlet::Var, 18>().unwrap(),
+                str.to_lowercase().as_str(),
+                "{ch:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn try_extend() {
+    fn check<S: std::fmt::Debug + PartialEq<&'static str>>(
+        mut slet: S,
+        mut extend: impl FnMut(&mut S, &[&str]) -> Result<(), stringlet::error::Error>,
+    ) {
+        extend(&mut slet, &["ab", "", "ä"]).unwrap();
+        assert_eq!(slet, "abä");
+        assert!(extend(&mut slet, &["c", "d", "€"]).is_err());
+        assert_eq!(slet, "abä");
+        extend(&mut slet, &["c", "d"]).unwrap();
+        assert_eq!(slet, "abäcd");
+        assert!(extend(&mut slet, &["x"]).is_err());
+        assert_eq!(slet, "abäcd");
+    }
+    check(VarStringlet::<6>::new(), |slet, pieces| {
+        slet.try_extend(pieces.iter().copied())
+    });
+    check(SlimStringlet::<6>::new(), |slet, pieces| {
+        slet.try_extend(pieces.iter().copied())
+    });
+
+    // Padding must come out the same as a fresh value
+    let mut slet = SlimStringlet::<6>::try_from("ab").unwrap();
+    assert!(slet.try_extend("cdefg".chars()).is_err());
+    assert_eq!(slet, SlimStringlet::<6>::try_from("ab").unwrap());
+    let mut slet = VarStringlet::<6>::try_from("ab").unwrap();
+    assert!(slet.try_extend("cdefg".chars()).is_err());
+    assert_eq!(slet, VarStringlet::<6>::try_from("ab").unwrap());
+}
+
+#[test]
+fn trim_padding() {
+    use stringlet::Align::{Center, Left, Right};
+    for (str, align) in [
+        ("ab", Left),
+        ("ab", Right),
+        ("a b", Center),
+        ("", Center),
+        ("abcde", Left),
+    ] {
+        let slet = Stringlet::<5>::from_str_padded(str, ' ', align).unwrap();
+        assert_eq!(slet.trim_padding(' ', align), str);
+        let slet = SlimStringlet::<6>::from_str_padded(str, 'ä', align).unwrap();
+        assert_eq!(slet.trim_padding('ä', align), str);
+    }
+    // Fill on the other side is content
+    let slet = Stringlet::<5>::from_str_padded(" ab", ' ', Left).unwrap();
+    assert_eq!(slet.trim_padding(' ', Left), " ab");
+    let slet = Stringlet::<5>::from_str_padded("ab ", ' ', Right).unwrap();
+    assert_eq!(slet.trim_padding(' ', Right), "ab ");
+    for (str, trimmed) in [
+        ("42", "42"),
+        ("100", "100"),
+        ("0", "0"),
+        ("", "0"),
+        ("12345", "12345"),
+    ] {
+        let slet = Stringlet::<5>::from_str_zero_padded(str).unwrap();
+        assert_eq!(slet.trim_padding('0', Right), trimmed, "{str}");
+    }
+    let slet = Stringlet::<5>::from_str_zero_padded("-42").unwrap();
+    assert_eq!(slet.trim_padding('0', Right).parse(), Ok(-42));
+}
+
+#[test]
+fn substring() {
+    use stringlet::error::Error::{NotCharBoundary, OutOfBounds, ReversedRange};
+    use stringlet::{Fixed, Slim, Trim, Var};
+    let slet = stringlet!(slim 12: "Grüße");
+    let sub = |range: std::ops::Range<usize>| slet.substring::<Var, 8>(range);
+    assert_eq!(sub(0..0).unwrap(), "");
+    assert_eq!(sub(1..6).unwrap(), "rüß");
+    assert_eq!(
+        slet.substring::<Var, 8>(1..=4),
+        Err(NotCharBoundary { index: 5 })
+    );
+    assert_eq!(slet.substring::<Var, 8>(..), Ok(stringlet!(v 8: "Grüße")));
+    assert_eq!(sub(3..6), Err(NotCharBoundary { index: 3 }));
+    assert_eq!(sub(5..8), Err(OutOfBounds { index: 8, len: 7 }));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 6..5;
+    assert_eq!(sub(reversed), Err(ReversedRange { start: 6, end: 5 }));
+    assert_eq!(
+        slet.substring::<Var, 8>(9..),
+        Err(OutOfBounds { index: 9, len: 7 })
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 9..8;
+    assert_eq!(sub(reversed), Err(OutOfBounds { index: 9, len: 7 }));
+    assert!(matches!(
+        slet.substring::<Fixed, 2>(..),
+        Err(stringlet::error::Error::TooLong { len: 7, .. })
+    ));
+    let fixed: Stringlet<2> = slet.substring(2..4).unwrap();
+    assert_eq!(fixed, "ü");
+
+    assert_eq!(
+        slet.strip_prefix_into::<Slim, 8>("Gr"),
+        Some(Ok(stringlet!(slim 8: "üße")))
+    );
+    assert_eq!(slet.strip_prefix_into::<Slim, 8>("gr"), None);
+    assert_eq!(
+        slet.strip_suffix_into::<Trim, 4>("ße"),
+        Some(Ok(stringlet!(trim 4: "Grü")))
+    );
+    assert!(matches!(
+        slet.strip_suffix_into::<Trim, 2>("e"),
+        Some(Err(_))
+    ));
+
+    let chars = |first: Result<VarStringlet<8>, _>, last: Result<VarStringlet<8>, _>| {
+        [first.unwrap(), last.unwrap()]
+    };
+    assert_eq!(chars(slet.first_chars(0), slet.last_chars(0)), ["", ""]);
+    assert_eq!(
+        chars(slet.first_chars(3), slet.last_chars(3)),
+        ["Grü", "üße"]
+    );
+    assert_eq!(
+        chars(slet.first_chars(5), slet.last_chars(5)),
+        ["Grüße", "Grüße"]
+    );
+    assert_eq!(
+        chars(slet.first_chars(6), slet.last_chars(6)),
+        ["Grüße", "Grüße"]
+    );
+    let short: Result<VarStringlet<4>, _> = slet.last_chars(3);
+    assert!(short.is_err());
+    let empty: VarStringlet<0> = VarStringlet::<0>::new().last_chars(1).unwrap();
+    assert_eq!(empty, "");
+}
+
+#[test]
+fn split() {
+    use stringlet::error::Error::{FieldCount, TooLong};
+    use stringlet::{Fixed, Var};
+    let slet = stringlet!(slim 16: "a::bc::::dé");
+    let split = |pat: &str| -> Vec<String> {
+        slet.split_into::<Var, 12>(pat)
+            .map(|field| field.unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(split("::"), ["a", "bc", "", "dé"]);
+    assert_eq!(split(":"), "a::bc::::dé".split(':').collect::<Vec<_>>());
+    assert_eq!(split("x"), ["a::bc::::dé"]);
+    assert_eq!(
+        split(""),
+        [
+            "", "a", ":", ":", "b", "c", ":", ":", ":", ":", "d", "é", ""
+        ]
+    );
+    for str in ["", "a", "ab", "ä€", ",", "a,,b,", ",ä,"] {
+        let slet = VarStringlet::<8>::try_from(str).unwrap();
+        for pat in ["", ",", ",,", "ä", "x"] {
+            let pieces: Vec<_> = slet.split_into::<Var, 8>(pat).map(Result::unwrap).collect();
+            assert_eq!(
+                pieces,
+                str.split(pat).collect::<Vec<_>>(),
+                "{str:?} {pat:?}"
+            );
+        }
+    }
+    let mut fields = slet.split_into::<Var, 4>('c');
+    assert_eq!(fields.next(), Some(Ok(stringlet!(v 4: "a::b"))));
+    assert!(matches!(fields.next(), Some(Err(TooLong { len: 7, .. }))));
+    assert_eq!(fields.next(), None);
+    assert_eq!(
+        VarStringlet::<2>::new().split_into::<Var, 2>(&',').count(),
+        1
+    );
+
+    let words: Vec<_> = stringlet!(v 16: " ab\tc  d\n")
+        .split_whitespace_into::<Fixed, 1>()
+        .collect();
+    assert_eq!(words.len(), 3);
+    assert!(matches!(words[0], Err(TooLong { len: 2, .. })));
+    assert_eq!(words[1..], [Ok(stringlet!("c")), Ok(stringlet!("d"))]);
+
+    let rate = stringlet!(v 16: "EUR:USD:1.07");
+    let fields: [VarStringlet<4>; 3] = rate.split_array(':').unwrap();
+    assert_eq!(fields, ["EUR", "USD", "1.07"]);
+    assert_eq!(
+        rate.split_array::<Var, 4, 4>(':'),
+        Err(FieldCount {
+            count: 3,
+            expected: 4
+        })
+    );
+    assert_eq!(
+        rate.split_array::<Var, 4, 2>(':'),
+        Err(FieldCount {
+            count: 3,
+            expected: 2
+        })
+    );
+    assert!(matches!(
+        rate.split_array::<Fixed, 3, 3>(':'),
+        Err(TooLong { len: 4, .. })
+    ));
+}
+
+#[test]
+fn replace() {
+    use stringlet::{Slim, Var};
+    let slet = stringlet!(trim 9: "a.b.c.ä.");
+    assert_eq!(
+        slet.replace::<Var, 16>('.', "::"),
+        Ok(stringlet!(v 16: "a::b::c::ä::"))
+    );
+    assert_eq!(
+        slet.replace::<Var, 16>(".", ""),
+        Ok(stringlet!(v 16: "abcä"))
+    );
+    assert_eq!(
+        slet.replace::<Var, 24>("", "|"),
+        Ok(stringlet!(v 24: "|a|.|b|.|c|.|ä|.|"))
+    );
+    assert_eq!(
+        slet.replace::<Var, 16>(&'x', "y"),
+        Ok(stringlet!(v 16: "a.b.c.ä."))
+    );
+    assert_eq!(
+        slet.replacen::<Slim, 10>('.', "", 2),
+        Ok(stringlet!(slim 10: "abc.ä."))
+    );
+    assert_eq!(
+        slet.replacen::<Slim, 10>('.', "!", 0),
+        Ok(stringlet!(slim 10: "a.b.c.ä."))
+    );
+    assert!(slet.replace::<Var, 8>('.', "::").is_err());
+    for str in ["", "a", "ä€", "a..b."] {
+        let slet = VarStringlet::<8>::try_from(str).unwrap();
+        for from in ["", ".", "..", "€"] {
+            assert_eq!(
+                slet.replace::<Var, 32>(from, "<>").unwrap(),
+                str.replace(from, "<>").as_str(),
+                "{str:?} {from:?}"
+            );
+            assert_eq!(
+                slet.replacen::<Var, 32>(from, "-", 2).unwrap(),
+                str.replacen(from, "-", 2).as_str()
+            );
+        }
+    }
+
+    let mut fixed = slet;
+    fixed.replace_char('.', '_').unwrap();
+    assert_eq!(fixed, "a_b_c_ä_");
+    fixed.replace_char('ä', 'ö').unwrap();
+    assert_eq!(fixed, "a_b_c_ö_");
+    fixed.replace_char('ö', 'o').unwrap();
+    assert_eq!(fixed, "a_b_c_o_");
+    assert!(fixed.replace_char('_', 'ü').is_err());
+    assert_eq!(fixed, "a_b_c_o_");
+    // padding kept intact for fast ==
+    let mut var = VarStringlet::<12>::try_from("x.y").unwrap();
+    var.replace_char('.', '€').unwrap();
+    assert_eq!(var, VarStringlet::<12>::try_from("x€y").unwrap());
+    var.replace_char('€', '-').unwrap();
+    assert_eq!(var, VarStringlet::<12>::try_from("x-y").unwrap());
+}
+
+#[test]
+fn widen() {
+    let fixed = stringlet!("abc");
+    let var: VarStringlet<8> = fixed.widen();
+    assert_eq!(var, fixed);
+    let trim: TrimStringlet<4> = fixed.widen();
+    assert_eq!(trim, fixed);
+    let slim: SlimStringlet<4> = trim.widen();
+    assert_eq!(slim, fixed);
+    let slim: SlimStringlet<3> = fixed.widen();
+    assert_eq!(slim, fixed);
+    let var: VarStringlet<4> = slim.widen();
+    assert_eq!(var, fixed);
+    let trim: TrimStringlet<3> = fixed.widen();
+    assert_eq!(trim, fixed);
+    let fixed: Stringlet<3> = fixed.widen();
+    assert_eq!(fixed, "abc");
+}
+
+#[test]
+fn narrow() {
+    use stringlet::error::Error::{TooLong, TooShort};
+    let var = stringlet!(var 8: "abc");
+    assert_eq!(var.narrow::<_, 3>(), Ok(stringlet!("abc")));
+    assert_eq!(var.narrow(), Ok(stringlet!(trim 4: "abc")));
+    assert_eq!(var.narrow(), Ok(stringlet!(slim 3: "abc")));
+    let slim: Result<SlimStringlet<2>, _> = var.narrow();
+    assert_eq!(
+        slim,
+        Err(TooLong {
+            len: 3,
+            min: 0,
+            max: 2,
+            kind: "SlimStringlet"
+        })
+    );
+    let fixed: Result<Stringlet<4>, _> = var.narrow();
+    assert_eq!(
+        fixed,
+        Err(TooShort {
+            len: 3,
+            min: 4,
+            max: 4,
+            kind: "Stringlet"
+        })
+    );
+}
+
+/// Mutate a variable kind step by step, checking each time against a freshly constructed value. As equality of
+/// same kinds compares the raw bytes, this also checks the padding.
+macro_rules! mutate {
+    ($kind:ident) => {{
+        fn fresh(str: &str) -> $kind<5> {
+            str.try_into().unwrap()
+        }
+        let mut slet = $kind::<5>::new();
+        assert_eq!(slet.capacity(), 5);
+        assert_eq!(slet.pop(), None);
+        slet.push('a').unwrap();
+        assert_eq!(slet, fresh("a"));
+        slet.push_str("bc").unwrap();
+        assert_eq!(slet, fresh("abc"));
+        assert!(slet.push_str("def").is_err());
+        assert_eq!(slet, fresh("abc"));
+        slet.push('ä').unwrap();
+        assert_eq!(slet, fresh("abcä"));
+        assert!(slet.push('x').is_err());
+        assert_eq!(slet.pop(), Some('ä'));
+        assert_eq!(slet, fresh("abc"));
+        slet.insert(0, 'x').unwrap();
+        assert_eq!(slet, fresh("xabc"));
+        slet.insert_str(4, "y").unwrap();
+        assert_eq!(slet, fresh("xabcy"));
+        assert!(slet.insert(1, 'z').is_err());
+        assert_eq!(slet.remove(1), 'a');
+        assert_eq!(slet, fresh("xbcy"));
+        slet.retain(|ch| ch != 'b');
+        assert_eq!(slet, fresh("xcy"));
+        slet.truncate(4);
+        assert_eq!(slet, fresh("xcy"));
+        slet.truncate(1);
+        assert_eq!(slet, fresh("x"));
+        slet.clear();
+        assert_eq!(slet, fresh(""));
+        assert!(slet.is_empty());
+    }};
+}
+
+#[test]
+fn mutate() {
+    mutate!(VarStringlet);
+    mutate!(SlimStringlet);
+
+    let mut slim64 = SlimStringlet::<64>::new();
+    for _ in 0..64 {
+        slim64.push('x').unwrap();
+    }
+    assert_eq!(slim64.len(), 64);
+    assert_eq!(slim64.pop(), Some('x'));
+    assert_eq!(slim64.len(), 63);
+    slim64.clear();
+    assert_eq!(slim64, SlimStringlet::<64>::new());
+}
+
+#[test]
+#[should_panic]
+fn truncate_inside_char() {
+    let mut slet: VarStringlet<4> = "ä".try_into().unwrap();
+    slet.truncate(1);
+}
+
+#[test]
+#[should_panic]
+fn remove_at_end() {
+    let mut slet: SlimStringlet<4> = "ä".try_into().unwrap();
+    slet.remove(2);
+}
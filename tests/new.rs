@@ -31,6 +31,7 @@ fn default() {
 }
 
 #[test]
+#[cfg(feature = "alloc")]
 fn from_string() {
     let s: SlimStringlet<4> = String::from("hey").try_into().unwrap();
     assert_eq!(s.as_ref(), "hey");
@@ -56,9 +57,15 @@ fn try_from() {
 
 macro_rules! from_stringlet {
     ($slet:ident ($($short:ty),+) ($($ok:ty),+) ($($long:ty),*)) => {
-        $(assert_eq!(<$short>::from_stringlet($slet).unwrap_err(), TooShort, "TooShort {} {:?}", stringify!($short), $slet);)+
+        $(
+            let error = <$short>::from_stringlet($slet).unwrap_err();
+            assert!(matches!(error, TooShort { len, min, .. } if len == $slet.len() && min == <$short>::MIN_LEN), "TooShort {} {:?}", stringify!($short), $slet);
+        )+
         $(assert_eq!(<$ok>::from_stringlet($slet).unwrap(), $slet, "Ok {} {:?}", stringify!($ok), $slet);)+
-        $(assert_eq!(<$long>::from_stringlet($slet).unwrap_err(), TooLong, "TooLong {} {:?}", stringify!($long), $slet);)*
+        $(
+            let error = <$long>::from_stringlet($slet).unwrap_err();
+            assert!(matches!(error, TooLong { len, max, .. } if len == $slet.len() && max == <$long>::MAX_LEN), "TooLong {} {:?}", stringify!($long), $slet);
+        )*
     };
     ([$($slet:tt,)+] $short:tt $ok:tt $long:tt) => {
         $(
@@ -132,3 +139,226 @@ fn from_stringlet() {
         (Stringlet<255>, VarStringlet<255>, TrimStringlet<255>)
     }
 }
+
+#[test]
+fn from_str_truncating() {
+    use stringlet::error::Error::TooShort;
+    assert_eq!(
+        VarStringlet::<4>::from_str_truncating("Käse").unwrap(),
+        "Käs"
+    );
+    assert_eq!(
+        VarStringlet::<3>::from_str_truncating("Käse").unwrap(),
+        "Kä"
+    );
+    assert_eq!(SlimStringlet::<2>::from_str_truncating("€").unwrap(), "");
+    assert_eq!(
+        SlimStringlet::<2>::from_str_truncating("€").unwrap(),
+        SlimStringlet::<2>::new()
+    );
+    assert_eq!(
+        TrimStringlet::<4>::from_str_truncating("Käse").unwrap(),
+        "Käs"
+    );
+    assert_eq!(Stringlet::<3>::from_str_truncating("abcd").unwrap(), "abc");
+    assert_eq!(
+        Stringlet::<2>::from_str_truncating("Käse").unwrap_err(),
+        TooShort {
+            len: 1,
+            min: 2,
+            max: 2,
+            kind: "Stringlet"
+        }
+    );
+    assert!(TrimStringlet::<3>::from_str_truncating("a").is_err());
+    const CUT: VarStringlet<2> = match VarStringlet::from_str_truncating("abc") {
+        Ok(slet) => slet,
+        Err(_) => panic!(),
+    };
+    assert_eq!(CUT, "ab");
+}
+
+#[test]
+fn from_utf8_lossy() {
+    let lossy = |bytes: &[u8]| VarStringlet::<8>::from_utf8_lossy(bytes).unwrap();
+    for bytes in [
+        &b"abc"[..],
+        b"",
+        b"\xFF",
+        b"a\xFF\xFEb",
+        b"\xE2\x82",
+        b"a\xE2\x82b",
+        b"\xF0\x9F\xA6\x80\xC3",
+    ] {
+        assert_eq!(
+            lossy(bytes),
+            String::from_utf8_lossy(bytes).as_ref(),
+            "{bytes:?}"
+        );
+    }
+    assert_eq!(VarStringlet::<4>::from_utf8_lossy(b"ab\xFF").unwrap(), "ab");
+    assert_eq!(
+        SlimStringlet::<5>::from_utf8_lossy(b"ab\xFF").unwrap(),
+        "ab\u{FFFD}"
+    );
+    assert_eq!(
+        Stringlet::<3>::from_utf8_lossy(b"\xC3").unwrap(),
+        "\u{FFFD}"
+    );
+    assert!(Stringlet::<4>::from_utf8_lossy(b"\xC3\xC3").is_err());
+}
+
+#[test]
+fn from_str_padded() {
+    use stringlet::Align::{self, Center, Left, Right};
+    use stringlet::error::Error::{TooLong, TooShort};
+    // format!() pads by chars, not bytes, so ASCII only
+    for str in ["", "a", "ab", "abcd", "abcde"] {
+        for (align, expected) in [
+            (Left, format!("{str:_<5}")),
+            (Right, format!("{str:_>5}")),
+            (Center, format!("{str:_^5}")),
+        ] {
+            assert_eq!(
+                Stringlet::<5>::from_str_padded(str, '_', align).unwrap(),
+                expected.as_str()
+            );
+            assert_eq!(
+                VarStringlet::<5>::from_str_padded(str, '_', align).unwrap(),
+                expected.as_str()
+            );
+            assert_eq!(
+                TrimStringlet::<5>::from_str_padded(str, '_', align).unwrap(),
+                expected.as_str()
+            );
+            assert_eq!(
+                SlimStringlet::<5>::from_str_padded(str, '_', align).unwrap(),
+                expected.as_str()
+            );
+        }
+    }
+    assert_eq!(
+        Stringlet::<5>::from_str_padded("äb", '_', Center).unwrap(),
+        "_äb_"
+    );
+    assert_eq!(
+        Stringlet::<7>::from_str_padded("a", 'ä', Center).unwrap(),
+        "äaää"
+    );
+    assert_eq!(
+        Stringlet::<6>::from_str_padded("ab", '€', Right).unwrap_err(),
+        TooShort {
+            len: 5,
+            min: 6,
+            max: 6,
+            kind: "Stringlet"
+        }
+    );
+    // A multibyte fill that doesn’t add up pads as far as whole chars go
+    assert_eq!(
+        TrimStringlet::<5>::from_str_padded("abcd", 'ä', Left).unwrap(),
+        "abcd"
+    );
+    assert_eq!(
+        VarStringlet::<6>::from_str_padded("a", '€', Center).unwrap(),
+        "a€"
+    );
+    assert_eq!(
+        TrimStringlet::<7>::from_str_padded("ab", '€', Left).unwrap_err(),
+        TooShort {
+            len: 5,
+            min: 6,
+            max: 7,
+            kind: "TrimStringlet"
+        }
+    );
+    assert_eq!(
+        Stringlet::<2>::from_str_padded("abc", ' ', Align::Left).unwrap_err(),
+        TooLong {
+            len: 3,
+            min: 2,
+            max: 2,
+            kind: "Stringlet"
+        }
+    );
+}
+
+#[test]
+fn from_str_zero_padded() {
+    for str in ["", "0", "7", "42", "-42", "+42", "-1234", "12345"] {
+        let num: i32 = str.parse().unwrap_or_default();
+        let expected = if str.starts_with('+') {
+            format!("{num:+05}")
+        } else {
+            format!("{num:05}")
+        };
+        if str.is_empty() {
+            assert_eq!(Stringlet::<5>::from_str_zero_padded(str).unwrap(), "00000");
+        } else {
+            assert_eq!(
+                Stringlet::<5>::from_str_zero_padded(str).unwrap(),
+                expected.as_str(),
+                "{str}"
+            );
+        }
+    }
+    assert!(Stringlet::<3>::from_str_zero_padded("-123").is_err());
+    for (sign, expected) in [("-", "-0000"), ("+", "+0000")] {
+        let slet = Stringlet::<5>::from_str_zero_padded(sign).unwrap();
+        assert_eq!(slet, expected);
+        assert_eq!(slet.as_str().parse(), Ok(0));
+    }
+    assert_eq!(Stringlet::<1>::from_str_zero_padded("-").unwrap(), "-");
+}
+
+#[test]
+fn try_from_iter() {
+    use stringlet::error::Error::{TooLong, TooShort};
+    let str = "a-b-ä-€";
+    let filtered = || str.chars().filter(|&ch| ch != '-');
+    assert_eq!(
+        VarStringlet::<8>::try_from_iter(filtered()).unwrap(),
+        "abä€"
+    );
+    assert_eq!(
+        SlimStringlet::<7>::try_from_iter(filtered()).unwrap(),
+        "abä€"
+    );
+    assert_eq!(Stringlet::<7>::try_from_iter(filtered()).unwrap(), "abä€");
+    assert_eq!(
+        TrimStringlet::<8>::try_from_iter(filtered()).unwrap(),
+        "abä€"
+    );
+    assert_eq!(
+        VarStringlet::<6>::try_from_iter(filtered()).unwrap_err(),
+        TooLong {
+            len: 7,
+            min: 0,
+            max: 6,
+            kind: "VarStringlet"
+        }
+    );
+    assert_eq!(
+        Stringlet::<8>::try_from_iter(filtered()).unwrap_err(),
+        TooShort {
+            len: 7,
+            min: 8,
+            max: 8,
+            kind: "Stringlet"
+        }
+    );
+    assert_eq!(
+        VarStringlet::<8>::try_from_iter(str.split('-')).unwrap(),
+        "abä€"
+    );
+    assert_eq!(
+        VarStringlet::<8>::try_from_iter(['a', 'b'].iter()).unwrap(),
+        "ab"
+    );
+    assert_eq!(
+        VarStringlet::<8>::try_from_iter(core::iter::empty::<char>()).unwrap(),
+        ""
+    );
+    // Stops early, even on an endless iterator
+    assert!(VarStringlet::<8>::try_from_iter(core::iter::repeat('x')).is_err());
+}
@@ -0,0 +1,104 @@
+//! Test functionality of the `ops` module.
+
+use stringlet::concat;
+use stringlet::error::Error::TooLong;
+use stringlet::prelude::*;
+
+#[test]
+fn concat_kinds() {
+    let fixed = stringlet!("ab");
+    let var = stringlet!(var 4: "cd");
+    let slim = stringlet!(slim 4: "");
+    assert_eq!(concat::<Stringlet<4>>(fixed, var).unwrap(), "abcd");
+    assert_eq!(concat::<VarStringlet<8>>(&fixed, "ä").unwrap(), "abä");
+    assert_eq!(concat::<TrimStringlet<5>>("äb", &var).unwrap(), "äbcd");
+    assert_eq!(concat::<SlimStringlet<2>>(slim, fixed).unwrap(), "ab");
+    assert_eq!(
+        concat::<SlimStringlet<2>>(slim, slim).unwrap(),
+        SlimStringlet::<2>::new()
+    );
+    assert_eq!(
+        concat::<VarStringlet<3>>(fixed, var).unwrap_err(),
+        TooLong {
+            len: 4,
+            min: 0,
+            max: 3,
+            kind: "VarStringlet"
+        }
+    );
+    assert!(concat::<Stringlet<5>>(fixed, var).is_err());
+}
+
+#[test]
+fn add() {
+    let var = VarStringlet::<8>::new();
+    let var = (var + "ab").unwrap();
+    let var = (var + &stringlet!("c")).unwrap();
+    let var = (var + &stringlet!(slim 4: "")).unwrap();
+    assert_eq!(var, "abc");
+    let var = ((var + "-").unwrap() + &stringlet!(trim 4: "äö")).unwrap();
+    assert_eq!(var, VarStringlet::<8>::try_from("abc-äö").unwrap());
+
+    let slim = (SlimStringlet::<4>::new() + &var.as_str()[..3]).unwrap();
+    assert_eq!(
+        (slim + "d").unwrap(),
+        SlimStringlet::<4>::try_from("abcd").unwrap()
+    );
+    assert_eq!(
+        stringlet!(slim 4: "abc") + "de",
+        Err(TooLong {
+            len: 5,
+            min: 0,
+            max: 4,
+            kind: "SlimStringlet"
+        })
+    );
+}
+
+#[test]
+fn add_assign() {
+    let mut var = VarStringlet::<8>::new();
+    var += "ab";
+    var += &stringlet!("c");
+    var += &stringlet!(slim 4: "");
+    var += &stringlet!(trim 4: "äö");
+    assert_eq!(var, VarStringlet::<8>::try_from("abcäö").unwrap());
+
+    let mut slim = SlimStringlet::<4>::new();
+    slim += &var.as_str()[..3];
+    slim += "d";
+    assert_eq!(slim, SlimStringlet::<4>::try_from("abcd").unwrap());
+}
+
+#[test]
+#[should_panic = "too long: 5 bytes for SlimStringlet, which takes 0..=4"]
+fn add_assign_too_long() {
+    let mut slim = stringlet!(slim 4: "abc");
+    slim += "de";
+}
+
+#[test]
+fn concat_macro() {
+    const REGION: Stringlet<2> = stringlet!("EU");
+    const CODE: SlimStringlet<4> = stringlet!(s 4: "FR");
+    const KEY: VarStringlet<8> = stringlet!(concat: REGION, "-", CODE, '-', 7,);
+    assert_eq!(KEY, "EU-FR-7");
+    const FIXED: Stringlet<5> = stringlet!(concat: REGION, "-", CODE);
+    assert_eq!(FIXED, "EU-FR");
+    let code = VarStringlet::<4>::try_from("DE").unwrap();
+    let key: SlimStringlet<5> = stringlet!(concat: REGION, "-", code);
+    assert_eq!(key, "EU-DE");
+    const SEP: &str = "::";
+    const NAMESPACED: VarStringlet<8> = stringlet!(concat: REGION, SEP, &CODE);
+    assert_eq!(NAMESPACED, "EU::FR");
+    let sep = String::from("/");
+    let path: VarStringlet<8> = stringlet!(concat: REGION, sep.as_str(), code);
+    assert_eq!(path, "EU/DE");
+}
+
+#[test]
+#[should_panic = "stringlet!(concat: …): too short: 4 bytes for Stringlet, which takes 5..=5"]
+fn concat_macro_too_short() {
+    let code = VarStringlet::<4>::try_from("D").unwrap();
+    let _: Stringlet<5> = stringlet!(concat: "EU", '-', code);
+}
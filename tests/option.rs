@@ -0,0 +1,67 @@
+//! Test functionality of the `option` module.
+
+use stringlet::prelude::*;
+use stringlet::{OptionSlimStringlet, OptionStringlet, OptionTrimStringlet, OptionVarStringlet};
+
+#[test]
+fn size() {
+    assert_eq!(size_of::<OptionStringlet<1>>(), size_of::<Stringlet<1>>());
+    assert_eq!(size_of::<OptionStringlet<3>>(), 3);
+    assert_eq!(size_of::<Option<Stringlet<3>>>(), 4);
+    assert_eq!(
+        size_of::<OptionVarStringlet<8>>(),
+        size_of::<VarStringlet<8>>()
+    );
+    assert_eq!(
+        size_of::<OptionTrimStringlet<2>>(),
+        size_of::<TrimStringlet<2>>()
+    );
+    assert_eq!(
+        size_of::<OptionSlimStringlet<64>>(),
+        size_of::<SlimStringlet<64>>()
+    );
+}
+
+macro_rules! option {
+    ($option:ident $($a:tt)+) => {
+        $(
+            let a = stringlet!$a;
+            let mut option = $option::some(a);
+            assert!(option.is_some(), "{a:?}");
+            assert!(!option.is_none());
+            assert_eq!(option.as_ref(), Some(&a));
+            assert_eq!(option, a.into());
+            assert_ne!(option, $option::NONE);
+            assert!(option > $option::NONE);
+            assert_eq!(option.replace(a), Some(a));
+            assert_eq!(option.take(), Some(a));
+            assert!(option.is_none());
+            assert_eq!(option.as_ref(), None);
+            assert_eq!(option, $option::none());
+            assert_eq!(option, None.into());
+            assert_eq!(option.replace(a), None);
+            assert_eq!(Option::from(option), Some(a));
+        )+
+    };
+}
+
+#[test]
+fn some_none() {
+    option!(OptionStringlet("a")("\u{7f}")("ÿ")("🦀")("abc"));
+    option!(OptionVarStringlet (v 1: "") (v 1: "a") (v 4: "") (v 4: "ÿ") (v 255: ""));
+    option!(OptionTrimStringlet (t 1: "") (t 1: "a") (t 2: "a") (t 2: "ÿ") (t 4: "🦀"));
+    option!(OptionSlimStringlet (s 1: "") (s 1: "a") (s 2: "") (s 2: "ÿ") (s 63: "") (s 64: ""));
+}
+
+#[test]
+fn traits() {
+    let mut option = OptionVarStringlet::<4>::default();
+    assert_eq!(format!("{option:?}"), "None");
+    option.replace(stringlet!(var 4: "ab"));
+    assert_eq!(
+        format!("{option:?}"),
+        "Some(VarStringlet<4> { str: \"ab\" })"
+    );
+    option.as_mut().unwrap().push('c').unwrap();
+    assert_eq!(option.into_option().unwrap(), "abc");
+}
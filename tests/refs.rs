@@ -29,6 +29,10 @@ fn borrow() {
             let str: &str = $a.as_ref();
             let slet: &Stringlet<$size> = $a.as_ref();
             assert_eq!(str.as_ptr(), slet.as_ptr(), "fail {}", stringify!($in));
+            let slet: &TrimStringlet<$size> = $a.as_ref();
+            assert_eq!(str.as_ptr(), slet.as_ptr(), "fail {}", stringify!($in));
+            let slet: &SlimStringlet<$size> = $a.as_ref();
+            assert_eq!(str.as_ptr(), slet.as_ptr(), "fail {}", stringify!($in));
         };
         ($a:expr) => {
             test_borrow!(a = $a, 3);
@@ -37,9 +41,56 @@ fn borrow() {
     }
     test_borrow!("aha");
 
-    test_borrow!(String::from("aha"));
+    #[cfg(feature = "alloc")]
+    {
+        test_borrow!(String::from("aha"));
+
+        test_borrow!(Box::<str>::from("aha"));
+    }
+}
 
-    test_borrow!(Box::<str>::from("aha"));
+#[test]
+fn from_str_ref() {
+    use stringlet::error::Error::{TooLong, TooShort};
+
+    // At full length the last byte is UTF-8, which can never be mistaken for a tag
+    for str in ["abc", "aä", "€", "ÿ", "\u{7ff}"] {
+        macro_rules! check {
+            ($($stringlet:ident)+) => {
+                $(
+                    let slet = $stringlet::<{ "€".len() }>::try_from(str).ok();
+                    let cast = $stringlet::<3>::from_str_ref(str);
+                    if str.len() == 3 {
+                        let cast = cast.unwrap();
+                        assert_eq!(cast.len(), 3, "{str:?}");
+                        assert_eq!(cast, str);
+                        assert_eq!(Some(cast), slet.as_ref());
+                    } else {
+                        assert_eq!(
+                            cast.unwrap_err(),
+                            TooShort { len: 2, min: 3, max: 3, kind: stringify!($stringlet) },
+                            "{str:?}"
+                        );
+                    }
+                    assert!(matches!($stringlet::<1>::from_str_ref(str), Err(TooLong { min: 1, max: 1, .. })));
+                )+
+            };
+        }
+        check!(Stringlet TrimStringlet SlimStringlet);
+    }
+    let long = "x".repeat(62) + "ÿ";
+    let slet = SlimStringlet::<64>::from_str_ref(&long).unwrap();
+    assert_eq!(slet.len(), 64);
+    assert_eq!(slet, long.as_str());
+}
+
+#[test]
+fn from_str_mut() {
+    let mut string = String::from("abcdef");
+    let slet = Stringlet::<3>::from_str_mut(&mut string[3..]).unwrap();
+    *slet = stringlet!("xyz");
+    assert_eq!(string, "abcxyz");
+    assert!(Stringlet::<3>::from_str_mut(&mut string[1..]).is_err());
 }
 
 #[test]
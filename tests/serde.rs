@@ -0,0 +1,48 @@
+//! Test functionality of the `serde` module.
+
+#![cfg(feature = "serde")]
+
+use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+use stringlet::prelude::*;
+
+#[test]
+fn round_trip() {
+    assert_tokens(&stringlet!("abc"), &[Token::Str("abc")]);
+    assert_tokens(&stringlet!(var 5: "abc"), &[Token::Str("abc")]);
+    assert_tokens(&stringlet!(trim 4: "abc"), &[Token::Str("abc")]);
+    assert_tokens(&stringlet!(slim 5: "abc"), &[Token::Str("abc")]);
+    assert_tokens(&stringlet!(slim 5: ""), &[Token::Str("")]);
+}
+
+#[test]
+fn from_bytes() {
+    assert_de_tokens(&stringlet!(var 5: "abc"), &[Token::Bytes(b"abc")]);
+    assert_de_tokens_error::<VarStringlet<5>>(
+        &[Token::Bytes(b"\xFF")],
+        r#"invalid value: byte array, expected VarStringlet<5>, i.e. a str of 0..=5 bytes"#,
+    );
+}
+
+#[test]
+fn too_long() {
+    assert_de_tokens_error::<VarStringlet<2>>(
+        &[Token::Str("abc")],
+        "invalid length 3, expected VarStringlet<2>, i.e. a str of 0..=2 bytes",
+    );
+    assert_de_tokens_error::<SlimStringlet<2>>(
+        &[Token::Bytes(b"abc")],
+        "invalid length 3, expected SlimStringlet<2>, i.e. a str of 0..=2 bytes",
+    );
+}
+
+#[test]
+fn too_short() {
+    assert_de_tokens_error::<Stringlet<4>>(
+        &[Token::Str("abc")],
+        "invalid length 3, expected Stringlet<4>, i.e. a str of 4..=4 bytes",
+    );
+    assert_de_tokens_error::<TrimStringlet<5>>(
+        &[Token::Str("abc")],
+        "invalid length 3, expected TrimStringlet<5>, i.e. a str of 4..=5 bytes",
+    );
+}
@@ -0,0 +1,66 @@
+//! Test functionality of the `spill` module.
+
+#![cfg(feature = "alloc")]
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use stringlet::prelude::*;
+
+#[test]
+fn spill() {
+    let mut spill = SpillStringlet::<4>::new();
+    assert!(spill.is_empty());
+    spill.push_str("abc");
+    spill.push('d');
+    assert!(spill.is_inline());
+    assert_eq!(spill, stringlet!("abcd"));
+    spill.push('e');
+    assert!(!spill.is_inline());
+    assert_eq!(spill, "abcde");
+    assert_eq!(spill, stringlet!(var 8: "abcde"));
+    assert_eq!(spill.pop(), Some('e'));
+    assert!(!spill.is_inline());
+    assert_eq!(spill, SpillStringlet::from("abcd"));
+    spill.clear();
+    assert_eq!(spill, "");
+    assert_eq!(spill.into_string(), "");
+}
+
+#[test]
+fn from() {
+    assert!(SpillStringlet::<4>::from("abcd").is_inline());
+    assert!(!SpillStringlet::<4>::from("abcde").is_inline());
+    assert!(SpillStringlet::<4>::from(String::from("abcd")).is_inline());
+    assert!(!SpillStringlet::<4>::from(String::from("abcde")).is_inline());
+    let spill: SpillStringlet = stringlet!(var 16: "abc").into();
+    assert_eq!(String::from(spill), "abc");
+}
+
+#[test]
+fn traits() {
+    let mut spill = SpillStringlet::<4>::default();
+    write!(spill, "{}-{}", 12, 345).unwrap();
+    assert_eq!(spill, String::from("12-345"));
+    assert_eq!(format!("{spill:>8}"), "  12-345");
+    assert_eq!(format!("{spill:?}"), "Heap(\"12-345\")");
+    assert!(spill > "12");
+    let abc = SpillStringlet::from("abc");
+    assert!(spill < abc);
+    assert!("12" < spill);
+    assert_eq!("12-345", spill);
+    assert_eq!(*"12-345", spill);
+    assert_eq!(String::from("12-345"), spill);
+    assert_eq!(stringlet!(v 8: "12-345"), spill);
+    let two = String::from("2");
+    assert!(spill < two);
+    assert!(two > spill);
+    assert!(spill > stringlet!("1"));
+    assert!(stringlet!("1") < spill);
+
+    let set = HashSet::from([
+        SpillStringlet::<4>::from("abc"),
+        SpillStringlet::from("abcdef"),
+    ]);
+    assert!(set.contains("abc"));
+    assert!(set.contains("abcdef"));
+}
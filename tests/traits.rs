@@ -1,10 +1,85 @@
 //! Test functionality of the `traits` module.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{BuildHasher, RandomState};
 use stringlet::prelude::*;
 
 #[test]
 fn hasher() {
     let slet = SlimStringlet::<8>::try_from("hello").unwrap();
-    let hm = std::collections::HashSet::from([slet]);
+    let hm = HashSet::from([slet]);
     assert!(hm.contains(&slet));
 }
+
+#[test]
+fn hash_like_str() {
+    let state = RandomState::new();
+    for str in ["", "a", "hello", "hello world"] {
+        let hash = state.hash_one(str);
+        assert_eq!(
+            state.hash_one(VarStringlet::<16>::from_str(str).unwrap()),
+            hash
+        );
+        assert_eq!(
+            state.hash_one(SlimStringlet::<16>::from_str(str).unwrap()),
+            hash
+        );
+        assert_eq!(
+            state.hash_one(SlimStringlet::<64>::from_str(str).unwrap()),
+            hash
+        );
+    }
+    assert_eq!(state.hash_one(stringlet!("abc")), state.hash_one("abc"));
+    assert_eq!(
+        state.hash_one(stringlet!(trim 4: "abc")),
+        state.hash_one("abc")
+    );
+}
+
+#[test]
+fn borrow() {
+    let map = HashMap::from([
+        (stringlet!(var 8: "EUR"), 1.0),
+        (stringlet!(var 8: "USD"), 1.07),
+    ]);
+    assert_eq!(map.get("USD"), Some(&1.07));
+    assert_eq!(map.get("CHF"), None);
+
+    let map = BTreeMap::from([
+        (stringlet!(trim 3: "eo"), "Esperanto"),
+        (stringlet!(trim: "deu"), "Deutsch"),
+    ]);
+    assert_eq!(map.get("eo"), Some(&"Esperanto"));
+    assert_eq!(map.get("deu"), Some(&"Deutsch"));
+
+    let set = HashSet::from([AutoStringlet::from_str("abc").unwrap()]);
+    assert!(set.contains("abc"));
+}
+
+#[test]
+fn any_stringlet() {
+    fn check<S: AnyStringlet>(str: &str, consts: (usize, usize, usize, bool, &str, u8)) {
+        assert_eq!(
+            (
+                S::SIZE,
+                S::MIN_LEN,
+                S::MAX_LEN,
+                S::CAN_BE_EMPTY,
+                S::NAME,
+                S::ABBR
+            ),
+            consts
+        );
+        let slet = S::from_str(str).unwrap();
+        assert_eq!(AnyStringlet::as_str(&slet), str);
+        assert_eq!(AnyStringlet::len(&slet), str.len());
+        assert_eq!(AnyStringlet::is_empty(&slet), str.is_empty());
+        assert!(S::from_str(&"x".repeat(S::MAX_LEN + 1)).is_err());
+    }
+    check::<Stringlet<3>>("abc", (3, 3, 3, false, "Stringlet", b'F'));
+    check::<Stringlet<0>>("", (0, 0, 0, true, "Stringlet", b'F'));
+    check::<VarStringlet<4>>("", (4, 0, 4, true, "VarStringlet", b'V'));
+    check::<TrimStringlet<4>>("abc", (4, 3, 4, false, "TrimStringlet", b'T'));
+    check::<TrimStringlet<1>>("", (1, 0, 1, true, "TrimStringlet", b'T'));
+    check::<SlimStringlet<64>>("ab", (64, 0, 64, true, "SlimStringlet", b'S'));
+}